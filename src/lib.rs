@@ -24,12 +24,31 @@ mod parse;
 mod sentences;
 
 pub use crate::parse::{
-    parse, BwcData, GgaData, GllData, GsaData, GsvData, NmeaError, ParseResult, RmcData,
-    RmcStatusOfFix, TxtData, VtgData, SENTENCE_MAX_LEN,
+    decode_ais_payload, parse, parse_pgrme, parse_pgrmm, parse_pgrmz, parse_pmtk,
+    parse_proprietary, parse_psti, parse_pubx, AamData, AisClassBExtendedPositionReport,
+    AisClassBPositionReport, AisDimensions, AisMessage, AisNavigationStatus, AisPositionReport,
+    AisStaticAndVoyageData, AisStaticDataPart, AisStaticDataReport, AlmData, ApbBearing, ApbData,
+    BodData, BwcData, BwwData, DbkData, DbsData, DbtData, DptData, DtmData, GbsData, GgaData,
+    GllData, GnsData, GnsMode, GnsNavStatus, GrsData, GrsMode, GsaData, GstData, GsvData, HdgData,
+    HdmData, HdtData, HeadingReference, HeadingSensorReading, HmrData, HmsData, IntegrityStatus,
+    MtwData, MwdData, MwvData, MwvReference, NmeaError, ParseResult, PgrmeData, PgrmmData,
+    PgrmzData, PmtkAckData, PmtkAckFlag, PmtkCommand, PmtkData, PmtkReleaseData,
+    ProprietarySentence, PstiAttitudeData, PstiBaselineData, PstiData, PstiRtkPositionData,
+    PubxData, PubxNavStatus, PubxPositionData, PubxSatellite, PubxSatelliteStatus,
+    PubxSatellitesData, PubxTimeData, RmbData, RmcData, RmcStatusOfFix, RotData, RpmData,
+    RpmSource, RsaData, RteData, RteMode, SteerDirection, TargetAcquisition, TargetReference,
+    TargetStatus, TllData, TtmData, TxtData, VbwData, VdmData, VhwData, VlwData, VpwData, VtgData,
+    VwrData, WcvData, WncData, WplData, XdrData, XdrMeasurement, XdrTransducer, XteData, ZdaData,
+    ZdlData, ZdlPointType, ZfoData, ZtgData, PROPRIETARY_SENTENCE_MAX_LEN, SENTENCE_MAX_LEN,
 };
-use chrono::{NaiveDate, NaiveTime};
+use arrayvec::ArrayString;
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
 use core::{fmt, iter::Iterator, mem, ops::BitOr};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+/// Decoder of the proprietary sentences of one manufacturer, it merges the
+/// decoded data into the parser state
+pub type ProprietaryDecoder = fn(&mut Nmea, &ProprietarySentence) -> Result<(), NmeaError<'static>>;
 
 /// NMEA parser
 #[derive(Default, Debug, Clone)]
@@ -40,19 +59,68 @@ pub struct Nmea {
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
     pub altitude: Option<f32>,
+    /// Barometric altitude from Garmin PGRMZ, meters
+    pub baro_altitude: Option<f32>,
     pub speed_over_ground: Option<f32>,
     pub true_course: Option<f32>,
+    pub heading_true: Option<f32>,
+    pub heading_magnetic: Option<f32>,
+    pub rate_of_turn: Option<f32>,
+    pub rudder_angle: Option<f32>,
+    pub port_rudder_angle: Option<f32>,
+    pub speed_through_water: Option<f32>,
+    pub transverse_speed_through_water: Option<f32>,
+    pub log: LogDistance,
+    pub depth_below_transducer: Option<f32>,
+    pub depth_below_keel: Option<f32>,
+    pub depth_below_surface: Option<f32>,
+    pub transducer_offset: Option<f32>,
     pub num_of_fix_satellites: Option<u32>,
     pub hdop: Option<f32>,
     pub vdop: Option<f32>,
     pub pdop: Option<f32>,
     pub geoid_height: Option<f32>,
+    pub latitude_error: Option<f32>,
+    pub longitude_error: Option<f32>,
+    pub altitude_error: Option<f32>,
+    horizontal_position_error: Option<f32>,
+    vertical_position_error: Option<f32>,
+    spherical_position_error: Option<f32>,
     pub satellites: Vec<Satellite>,
     pub fix_satellites_prns: Option<Vec<u32>>,
+    pub navigation: NavigationState,
+    pub wind: WindState,
+    pub heading_monitor: HeadingMonitorState,
+    pub water_temperature: Option<f32>,
+    waypoints: HashMap<ArrayString<[u8; 64]>, (f64, f64)>,
+    route_scan: Vec<Option<RteData>>,
+    route: Option<Route>,
+    ais_fragments: HashMap<(bool, Option<u8>), Vec<VdmData>>,
+    ais_message: Option<AisMessage>,
+    horizontal_accuracy: Option<f32>,
+    vertical_accuracy: Option<f32>,
+    pmtk_response: Option<PmtkData>,
+    targets: BTreeMap<u8, Target>,
+    almanac: BTreeMap<u8, AlmData>,
+    almanac_scan: Option<u16>,
+    almanac_complete: bool,
     satellites_scan: HashMap<GnssType, Vec<Vec<Satellite>>>,
+    satellite_residuals: HashMap<(Option<GnssType>, u32), f32>,
+    fix_satellites_prns_by_system: HashMap<Option<u8>, Vec<u32>>,
     required_sentences_for_nav: SentenceMask,
     last_fix_time: Option<NaiveTime>,
     last_txt: Option<TxtData>,
+    last_xdr: Option<XdrData>,
+    datum: Option<DtmData>,
+    pub map_datum: Option<ArrayString<[u8; 32]>>,
+    pub rtk_age: Option<f32>,
+    pub rtk_ratio: Option<f32>,
+    baseline: Option<PstiBaselineData>,
+    attitude: Option<PstiAttitudeData>,
+    proprietary_decoders: HashMap<ArrayString<[u8; 3]>, ProprietaryDecoder>,
+    rpm: HashMap<(RpmSource, u8), RpmData>,
+    last_gbs: Option<GbsData>,
+    last_zda_date: Option<NaiveDate>,
     sentences_for_this_time: SentenceMask,
 }
 
@@ -131,6 +199,202 @@ impl<'a> Nmea {
         self.altitude
     }
 
+    /// Returns the barometric altitude in meters, from the altimeter of a
+    /// Garmin unit. None if not available.
+    pub fn baro_altitude(&self) -> Option<f32> {
+        self.baro_altitude
+    }
+
+    /// Returns the name of the map datum selected on a Garmin unit, as
+    /// reported by PGRMM. None if not available.
+    pub fn map_datum(&self) -> Option<&str> {
+        self.map_datum.as_ref().map(|datum| datum.as_str())
+    }
+
+    /// Returns the last datum reference. None if no DTM was received, in which
+    /// case positions are assumed to be WGS84.
+    pub fn datum(&self) -> Option<&DtmData> {
+        self.datum.as_ref()
+    }
+
+    /// Returns last fixed latitude in degrees corrected back to WGS84.
+    /// None if not fixed or if the datum offsets are not relative to WGS84.
+    pub fn wgs84_latitude(&self) -> Option<f64> {
+        let (lat_offset, _, _) = self.wgs84_offsets()?;
+        self.latitude.map(|lat| lat - lat_offset)
+    }
+
+    /// Returns last fixed longitude in degrees corrected back to WGS84.
+    /// None if not fixed or if the datum offsets are not relative to WGS84.
+    pub fn wgs84_longitude(&self) -> Option<f64> {
+        let (_, lon_offset, _) = self.wgs84_offsets()?;
+        self.longitude.map(|lon| {
+            let lon = lon - lon_offset;
+            if lon > 180. {
+                lon - 360.
+            } else if lon < -180. {
+                lon + 360.
+            } else {
+                lon
+            }
+        })
+    }
+
+    /// Returns altitude from last fix corrected back to WGS84.
+    /// None if not available or if the datum offsets are not relative to WGS84.
+    pub fn wgs84_altitude(&self) -> Option<f32> {
+        let (_, _, alt_offset) = self.wgs84_offsets()?;
+        self.altitude.map(|alt| alt - alt_offset)
+    }
+
+    fn wgs84_offsets(&self) -> Option<(f64, f64, f32)> {
+        match self.datum {
+            Some(ref datum) => datum.wgs84_offsets(),
+            None => Some((0., 0., 0.)),
+        }
+    }
+
+    /// Returns the last heading in degrees relative to true north.
+    /// None if not available.
+    pub fn heading_true(&self) -> Option<f32> {
+        self.heading_true
+    }
+
+    /// Returns the last heading, pitch and roll of a dual antenna SkyTraq
+    /// receiver. None if not available.
+    pub fn attitude(&self) -> Option<&PstiAttitudeData> {
+        self.attitude.as_ref()
+    }
+
+    /// Returns the last baseline vector between the antennas of a SkyTraq
+    /// RTK receiver. None if not available.
+    pub fn baseline(&self) -> Option<&PstiBaselineData> {
+        self.baseline.as_ref()
+    }
+
+    /// Returns the age in seconds of the RTK corrections used for the last
+    /// fix. None if not available.
+    pub fn rtk_age(&self) -> Option<f32> {
+        self.rtk_age
+    }
+
+    /// Returns the ratio of the RTK ambiguity resolution of the last fix.
+    /// None if not available.
+    pub fn rtk_ratio(&self) -> Option<f32> {
+        self.rtk_ratio
+    }
+
+    /// Returns the last heading in degrees relative to magnetic north.
+    /// None if not available.
+    pub fn heading_magnetic(&self) -> Option<f32> {
+        self.heading_magnetic
+    }
+
+    /// Returns the last rate of turn in degrees per minute, negative when
+    /// the bow turns to port.
+    /// None if not available.
+    pub fn rate_of_turn(&self) -> Option<f32> {
+        self.rate_of_turn
+    }
+
+    /// Returns the last angle of the starboard or single rudder in degrees,
+    /// negative when the bow turns to port.
+    /// None if not available.
+    pub fn rudder_angle(&self) -> Option<f32> {
+        self.rudder_angle
+    }
+
+    /// Returns the last angle of the port rudder in degrees, negative when
+    /// the bow turns to port.
+    /// None if not available.
+    pub fn port_rudder_angle(&self) -> Option<f32> {
+        self.port_rudder_angle
+    }
+
+    /// Returns the last valid RPM report of the engine or shaft with the
+    /// given number
+    pub fn rpm(&self, source: RpmSource, number: u8) -> Option<&RpmData> {
+        self.rpm.get(&(source, number))
+    }
+
+    /// Returns the last water temperature in degrees Celsius.
+    /// None if not available.
+    pub fn water_temperature(&self) -> Option<f32> {
+        self.water_temperature
+    }
+
+    /// Returns the last speed through water in knots.
+    /// None if not available.
+    pub fn speed_through_water(&self) -> Option<f32> {
+        self.speed_through_water
+    }
+
+    /// Returns the last transverse speed through water in knots, positive
+    /// to starboard.
+    /// None if not available.
+    pub fn transverse_speed_through_water(&self) -> Option<f32> {
+        self.transverse_speed_through_water
+    }
+
+    /// Returns the last distances travelled by the vessel
+    pub fn log(&self) -> &LogDistance {
+        &self.log
+    }
+
+    /// Returns the current as set, degrees true the water flows to, and
+    /// drift in knots. It is the difference between the motion over ground
+    /// and the motion through water along the true heading, including
+    /// leeway if VBW reported a transverse water speed.
+    pub fn current(&self) -> Option<(f32, f32)> {
+        let heading = self.heading_true?.to_radians();
+        let speed = self.speed_through_water?;
+        let transverse_speed = self.transverse_speed_through_water.unwrap_or(0.);
+        let course = self.true_course?.to_radians();
+        let speed_over_ground = self.speed_over_ground?;
+
+        let east = speed_over_ground * course.sin()
+            - (speed * heading.sin() + transverse_speed * heading.cos());
+        let north = speed_over_ground * course.cos()
+            - (speed * heading.cos() - transverse_speed * heading.sin());
+        Some((
+            east.atan2(north).to_degrees().rem_euclid(360.),
+            east.hypot(north),
+        ))
+    }
+
+    /// Returns the last water depth below the transducer in meters.
+    /// None if not available.
+    pub fn depth_below_transducer(&self) -> Option<f32> {
+        self.depth_below_transducer
+    }
+
+    /// Returns the last water depth below the keel in meters.
+    /// A negative DPT offset is the distance from the transducer to the keel
+    /// and takes precedence over DBK.
+    pub fn depth_below_keel(&self) -> Option<f32> {
+        match (self.depth_below_transducer, self.transducer_offset) {
+            (Some(depth), Some(offset)) if offset < 0. => Some(depth + offset),
+            _ => self.depth_below_keel,
+        }
+    }
+
+    /// Returns the last water depth below the surface in meters.
+    /// A positive DPT offset is the distance from the transducer to the water
+    /// line and takes precedence over DBS.
+    pub fn depth_below_surface(&self) -> Option<f32> {
+        match (self.depth_below_transducer, self.transducer_offset) {
+            (Some(depth), Some(offset)) if offset >= 0. => Some(depth + offset),
+            _ => self.depth_below_surface,
+        }
+    }
+
+    /// Returns the last DPT offset from the transducer in meters, positive
+    /// to the water line and negative to the keel.
+    /// None if not available.
+    pub fn transducer_offset(&self) -> Option<f32> {
+        self.transducer_offset
+    }
+
     /// Returns the number of satellites use for fix.
     pub fn fix_satellites(&self) -> Option<u32> {
         self.num_of_fix_satellites
@@ -146,11 +410,353 @@ impl<'a> Nmea {
         self.geoid_height
     }
 
+    /// Returns the standard deviation of the latitude error in meters (GST)
+    pub fn latitude_error(&self) -> Option<f32> {
+        self.latitude_error
+    }
+
+    /// Returns the standard deviation of the longitude error in meters (GST)
+    pub fn longitude_error(&self) -> Option<f32> {
+        self.longitude_error
+    }
+
+    /// Returns the standard deviation of the altitude error in meters (GST)
+    pub fn altitude_error(&self) -> Option<f32> {
+        self.altitude_error
+    }
+
+    /// Returns the horizontal position accuracy in meters (2D RMS of the
+    /// latitude and longitude error standard deviations from GST, else the
+    /// accuracy estimate from u-blox PUBX,00, else the Garmin PGRME
+    /// estimated error)
+    pub fn horizontal_accuracy(&self) -> Option<f32> {
+        match (self.latitude_error, self.longitude_error) {
+            (Some(lat_err), Some(lon_err)) => Some(lat_err.hypot(lon_err)),
+            _ => self.horizontal_accuracy.or(self.horizontal_position_error),
+        }
+    }
+
+    /// Returns the vertical position accuracy in meters (altitude error
+    /// standard deviation from GST, else the accuracy estimate from u-blox
+    /// PUBX,00, else the Garmin PGRME estimated error)
+    pub fn vertical_accuracy(&self) -> Option<f32> {
+        self.altitude_error
+            .or(self.vertical_accuracy)
+            .or(self.vertical_position_error)
+    }
+
+    /// Returns the estimated spherical position error in meters (Garmin PGRME)
+    pub fn spherical_accuracy(&self) -> Option<f32> {
+        self.spherical_position_error
+    }
+
     /// Returns the height of geoid above WGS84
     pub fn satellites(&self) -> Vec<Satellite> {
         self.satellites.clone()
     }
 
+    fn merge_bwc_data(&mut self, bwc: BwcData) {
+        let nav = &mut self.navigation;
+        nav.set_destination(bwc.waypoint_id);
+        nav.destination_latitude = bwc.latitude;
+        nav.destination_longitude = bwc.longitude;
+        nav.bearing_to_destination_true = bwc.true_bearing;
+        nav.bearing_to_destination_magnetic = bwc.magnetic_bearing;
+        nav.distance_to_destination = bwc.distance;
+    }
+
+    fn merge_bod_data(&mut self, bod: BodData) {
+        let nav = &mut self.navigation;
+        nav.set_destination(bod.destination_waypoint_id);
+        if bod.origin_waypoint_id.is_some() {
+            nav.origin_waypoint_id = bod.origin_waypoint_id;
+        }
+    }
+
+    fn merge_wcv_data(&mut self, wcv: WcvData) {
+        let nav = &mut self.navigation;
+        nav.set_destination(wcv.waypoint_id);
+        nav.closing_velocity = wcv.velocity;
+    }
+
+    fn merge_xte_data(&mut self, xte: XteData) {
+        if xte.valid {
+            let nav = &mut self.navigation;
+            nav.cross_track_error = xte.cross_track_error;
+            nav.steer_direction = xte.steer_direction;
+        }
+    }
+
+    fn merge_aam_data(&mut self, aam: AamData) {
+        let nav = &mut self.navigation;
+        nav.set_destination(aam.waypoint_id);
+        nav.arrived = Some(aam.arrival_circle_entered);
+    }
+
+    fn merge_ztg_data(&mut self, ztg: ZtgData) {
+        let nav = &mut self.navigation;
+        nav.set_destination(ztg.destination_waypoint_id);
+        nav.time_to_go = ztg.time_to_go;
+    }
+
+    fn merge_rmb_data(&mut self, rmb: RmbData) {
+        if rmb.valid {
+            let nav = &mut self.navigation;
+            nav.set_destination(rmb.destination_waypoint_id);
+            nav.origin_waypoint_id = rmb.origin_waypoint_id;
+            nav.destination_latitude = rmb.destination_latitude;
+            nav.destination_longitude = rmb.destination_longitude;
+            nav.cross_track_error = rmb.cross_track_error;
+            nav.steer_direction = rmb.steer_direction;
+            nav.distance_to_destination = rmb.range_to_destination;
+            nav.bearing_to_destination_true = rmb.bearing_to_destination;
+            nav.closing_velocity = rmb.closing_velocity;
+            nav.arrived = Some(rmb.arrived);
+        }
+    }
+
+    fn merge_apb_data(&mut self, apb: ApbData) {
+        if apb.valid {
+            let nav = &mut self.navigation;
+            nav.set_destination(apb.destination_waypoint_id);
+            nav.cross_track_error = apb.cross_track_error;
+            nav.steer_direction = apb.steer_direction;
+            nav.arrived = Some(apb.arrival_circle_entered);
+            match apb.bearing_to_destination {
+                Some(ApbBearing::True(bearing)) => nav.bearing_to_destination_true = Some(bearing),
+                Some(ApbBearing::Magnetic(bearing)) => {
+                    nav.bearing_to_destination_magnetic = Some(bearing)
+                }
+                None => {}
+            }
+            match apb.heading_to_steer {
+                Some(ApbBearing::True(heading)) => {
+                    nav.heading_to_steer_true = Some(heading);
+                    nav.heading_to_steer_magnetic = None;
+                }
+                Some(ApbBearing::Magnetic(heading)) => {
+                    nav.heading_to_steer_true = None;
+                    nav.heading_to_steer_magnetic = Some(heading);
+                }
+                None => {
+                    nav.heading_to_steer_true = None;
+                    nav.heading_to_steer_magnetic = None;
+                }
+            }
+        }
+    }
+
+    fn merge_vdm_data(&mut self, vdm: VdmData) -> Result<(), NmeaError<'a>> {
+        let key = (vdm.own_vessel, vdm.message_id);
+        let mut fragments = if vdm.fragment_number == 1 {
+            Vec::new()
+        } else {
+            self.ais_fragments.remove(&key).unwrap_or_default()
+        };
+        // Drop the message if a fragment is missing
+        let in_order = match fragments.last() {
+            Some(last) => {
+                last.fragment_count == vdm.fragment_count
+                    && last.fragment_number + 1 == vdm.fragment_number
+            }
+            None => vdm.fragment_number == 1,
+        };
+        if !in_order {
+            return Ok(());
+        }
+
+        let fill_bits = vdm.fill_bits;
+        let complete = vdm.fragment_number == vdm.fragment_count;
+        fragments.push(vdm);
+        if complete {
+            let payload: String = fragments.iter().map(|f| f.payload.as_str()).collect();
+            self.ais_message = Some(decode_ais_payload(&payload, fill_bits)?);
+        } else {
+            self.ais_fragments.insert(key, fragments);
+        }
+        Ok(())
+    }
+
+    fn merge_ttm_data(&mut self, ttm: TtmData) {
+        let time = ttm.time.or(self.fix_time);
+        if ttm.status == Some(TargetStatus::Lost) {
+            self.targets.remove(&ttm.target_number);
+        } else {
+            let target = self.target_entry(ttm.target_number);
+            target.distance = ttm.distance;
+            target.bearing = ttm.bearing;
+            target.bearing_reference = ttm.bearing_reference;
+            target.speed = ttm.speed;
+            target.course = ttm.course;
+            target.course_reference = ttm.course_reference;
+            target.cpa_distance = ttm.cpa_distance;
+            target.time_to_cpa = ttm.time_to_cpa;
+            if ttm.name.is_some() {
+                target.name = ttm.name;
+            }
+            target.status = ttm.status;
+            if time.is_some() {
+                target.last_update = time;
+            }
+        }
+        self.expire_targets(time);
+    }
+
+    fn merge_tll_data(&mut self, tll: TllData) {
+        let time = tll.time.or(self.fix_time);
+        if tll.status == Some(TargetStatus::Lost) {
+            self.targets.remove(&tll.target_number);
+        } else {
+            let target = self.target_entry(tll.target_number);
+            target.latitude = tll.latitude;
+            target.longitude = tll.longitude;
+            if tll.name.is_some() {
+                target.name = tll.name;
+            }
+            target.status = tll.status;
+            if time.is_some() {
+                target.last_update = time;
+            }
+        }
+        self.expire_targets(time);
+    }
+
+    fn target_entry(&mut self, number: u8) -> &mut Target {
+        self.targets.entry(number).or_insert_with(|| Target {
+            number,
+            ..Target::default()
+        })
+    }
+
+    /// Drop the targets that were not updated for `TARGET_TIMEOUT_SECS`
+    fn expire_targets(&mut self, now: Option<NaiveTime>) {
+        if let Some(now) = now {
+            self.targets.retain(|_, target| match target.last_update {
+                Some(last_update) => {
+                    // Updates up to half a day in the future are from
+                    // before midnight, the others are just reordered
+                    let age = (now - last_update).num_seconds().rem_euclid(24 * 3600);
+                    age <= TARGET_TIMEOUT_SECS || age > 12 * 3600
+                }
+                None => true,
+            });
+        }
+    }
+
+    fn merge_alm_data(&mut self, alm: AlmData) {
+        let (total_sentences, sentence_num) = (alm.total_sentences, alm.sentence_num);
+        // Track the order of the sentences to know when a dump is complete
+        self.almanac_scan = match self.almanac_scan {
+            _ if sentence_num == 1 => Some(1),
+            Some(received) if sentence_num == received + 1 => Some(sentence_num),
+            _ => None,
+        };
+        if sentence_num == 1 {
+            self.almanac_complete = false;
+        }
+        if self.almanac_scan == Some(total_sentences) {
+            self.almanac_complete = true;
+            self.almanac_scan = None;
+        }
+        // Satellites without an almanac are sent with empty fields
+        if alm.week.is_some() {
+            self.almanac.insert(alm.prn, alm);
+        }
+    }
+
+    fn merge_wpl_data(&mut self, wpl: WplData) {
+        if let (Some(id), Some(lat), Some(lon)) = (wpl.waypoint_id, wpl.latitude, wpl.longitude) {
+            self.waypoints.insert(id, (lat, lon));
+        }
+    }
+
+    fn merge_rte_data(&mut self, rte: RteData) {
+        let total = rte.total_sentences as usize;
+        let index = rte.sentence_num as usize - 1;
+        // A new transmission of the route starts, drop the incomplete one
+        if self.route_scan.len() != total || self.route_scan[index].is_some() {
+            self.route_scan.clear();
+            self.route_scan.resize(total, None);
+        }
+        self.route_scan[index] = Some(rte);
+
+        if self.route_scan.iter().all(Option::is_some) {
+            let mut parts = self.route_scan.drain(..).flatten();
+            let first = parts.next().expect("route has at least one sentence");
+            let mut route = Route {
+                route_id: first.route_id,
+                mode: first.mode,
+                waypoints: Vec::new(),
+            };
+            for id in first
+                .waypoint_ids
+                .into_iter()
+                .chain(parts.flat_map(|part| part.waypoint_ids))
+            {
+                route.waypoints.push(RouteWaypoint {
+                    id,
+                    latitude: None,
+                    longitude: None,
+                });
+            }
+            self.route = Some(route);
+        }
+    }
+
+    fn merge_dtm_data(&mut self, dtm: DtmData) {
+        self.datum = Some(dtm);
+    }
+
+    fn merge_pgrme_data(&mut self, pgrme: PgrmeData) {
+        self.horizontal_position_error = pgrme.horizontal;
+        self.vertical_position_error = pgrme.vertical;
+        self.spherical_position_error = pgrme.spherical;
+    }
+
+    fn merge_pgrmz_data(&mut self, pgrmz: PgrmzData) {
+        self.baro_altitude = pgrmz.altitude;
+    }
+
+    fn merge_pgrmm_data(&mut self, pgrmm: PgrmmData) {
+        self.map_datum = pgrmm.datum;
+    }
+
+    fn merge_psti_rtk_position_data(&mut self, psti: PstiRtkPositionData) {
+        self.fix_type = Some(psti.fix_type());
+        self.fix_time = psti.fix_time;
+        self.fix_date = psti.fix_date.map(|date| self.resolve_century(date));
+        self.latitude = psti.latitude;
+        self.longitude = psti.longitude;
+        self.altitude = psti.altitude;
+        self.rtk_age = psti.rtk_age;
+        self.rtk_ratio = psti.rtk_ratio;
+    }
+
+    fn merge_psti_baseline_data(&mut self, psti: PstiBaselineData) {
+        if psti.valid {
+            self.baseline = Some(psti);
+        }
+    }
+
+    fn merge_psti_attitude_data(&mut self, psti: PstiAttitudeData) {
+        if psti.is_valid() {
+            self.heading_true = psti.heading;
+            self.attitude = Some(psti);
+        }
+    }
+
+    fn merge_psti_data(&mut self, psti: PstiData) {
+        match psti {
+            PstiData::RtkPosition(psti) => self.merge_psti_rtk_position_data(psti),
+            PstiData::Baseline(psti) => self.merge_psti_baseline_data(psti),
+            PstiData::Attitude(psti) => self.merge_psti_attitude_data(psti),
+        }
+    }
+
+    fn merge_gbs_data(&mut self, gbs: GbsData) {
+        self.last_gbs = Some(gbs);
+    }
+
     fn merge_gga_data(&mut self, gga_data: GgaData) {
         self.fix_time = gga_data.fix_time;
         self.latitude = gga_data.latitude;
@@ -162,6 +768,39 @@ impl<'a> Nmea {
         self.geoid_height = gga_data.geoid_height;
     }
 
+    fn merge_gns_data(&mut self, gns_data: GnsData) {
+        self.fix_time = gns_data.fix_time;
+        self.latitude = gns_data.lat;
+        self.longitude = gns_data.lon;
+        self.fix_type = Some(gns_data.fix_type());
+        self.num_of_fix_satellites = gns_data.fix_satellites;
+        self.hdop = gns_data.hdop;
+        self.altitude = gns_data.altitude;
+        self.geoid_height = gns_data.geoid_height;
+    }
+
+    /// The altitude of PUBX,00 is above the ellipsoid, not above mean sea
+    /// level as the GGA altitude, so it is left out.
+    fn merge_pubx_position_data(&mut self, pubx: PubxPositionData) {
+        self.fix_time = Some(pubx.fix_time);
+        self.fix_type = Some(pubx.fix_type());
+        // Without a fix u-blox reports a position of 0, 0
+        if self.fix_type == Some(FixType::Invalid) {
+            self.latitude = None;
+            self.longitude = None;
+        } else {
+            self.latitude = pubx.latitude;
+            self.longitude = pubx.longitude;
+        }
+        self.num_of_fix_satellites = Some(pubx.fix_satellites);
+        self.speed_over_ground = Some(pubx.speed_over_ground);
+        self.true_course = Some(pubx.true_course);
+        self.hdop = Some(pubx.hdop);
+        self.vdop = Some(pubx.vdop);
+        self.horizontal_accuracy = Some(pubx.horizontal_accuracy);
+        self.vertical_accuracy = Some(pubx.vertical_accuracy);
+    }
+
     fn merge_gsv_data(&mut self, data: GsvData) -> Result<(), NmeaError<'a>> {
         {
             let d = self
@@ -171,13 +810,7 @@ impl<'a> Nmea {
             // Adjust size to this scan
             d.resize(data.number_of_sentences as usize, vec![]);
             // Replace data at index with new scan data
-            d.push(
-                data.sats_info
-                    .iter()
-                    .filter(|v| v.is_some())
-                    .map(|v| v.clone().unwrap())
-                    .collect(),
-            );
+            d.push(data.sats_info.iter().flatten().cloned().collect());
             d.swap_remove(data.sentence_num as usize - 1);
         }
         self.satellites.clear();
@@ -188,13 +821,14 @@ impl<'a> Nmea {
                 }
             }
         }
+        self.link_residuals();
 
         Ok(())
     }
 
     fn merge_rmc_data(&mut self, rmc_data: RmcData) {
         self.fix_time = rmc_data.fix_time;
-        self.fix_date = rmc_data.fix_date;
+        self.fix_date = rmc_data.fix_date.map(|date| self.resolve_century(date));
         self.fix_type = rmc_data.status_of_fix.map(|v| match v {
             RmcStatusOfFix::Autonomous => FixType::Gps,
             RmcStatusOfFix::Differential => FixType::DGps,
@@ -207,12 +841,55 @@ impl<'a> Nmea {
     }
 
     fn merge_gsa_data(&mut self, gsa: GsaData) {
+        self.fix_satellites_prns_by_system
+            .insert(gsa.system_id, gsa.fix_sats_prn.clone());
         self.fix_satellites_prns = Some(gsa.fix_sats_prn);
         self.hdop = gsa.hdop;
         self.vdop = gsa.vdop;
         self.pdop = gsa.pdop;
     }
 
+    fn merge_grs_data(&mut self, grs: GrsData) {
+        // With NMEA 4.1 each system has its own GSA and GRS
+        let prns_by_system = &self.fix_satellites_prns_by_system;
+        let prns = match prns_by_system
+            .get(&grs.system_id)
+            .or_else(|| prns_by_system.get(&None))
+        {
+            Some(prns) => prns,
+            None => return,
+        };
+        // Without a system ID the residuals are for all the constellations
+        let gnss_type = grs.gnss_type();
+        self.satellite_residuals
+            .retain(|(residual_gnss_type, _), _| {
+                gnss_type.is_some() && *residual_gnss_type != gnss_type
+            });
+        for (prn, residual) in prns.iter().zip(grs.residuals.iter()) {
+            if let Some(residual) = residual {
+                self.satellite_residuals
+                    .insert((gnss_type, *prn), *residual);
+            }
+        }
+        self.link_residuals();
+    }
+
+    fn link_residuals(&mut self) {
+        let residuals = &self.satellite_residuals;
+        for satellite in &mut self.satellites {
+            satellite.residual = residuals
+                .get(&(Some(satellite.gnss_type), satellite.prn))
+                .or_else(|| residuals.get(&(None, satellite.prn)))
+                .copied();
+        }
+    }
+
+    fn merge_gst_data(&mut self, gst: GstData) {
+        self.latitude_error = gst.lat_sd;
+        self.longitude_error = gst.lon_sd;
+        self.altitude_error = gst.alt_sd;
+    }
+
     fn merge_vtg_data(&mut self, vtg: VtgData) {
         self.speed_over_ground = vtg.speed_over_ground;
         self.true_course = vtg.true_course;
@@ -224,12 +901,193 @@ impl<'a> Nmea {
         self.fix_time = Some(gll.fix_time);
     }
 
+    fn merge_hdt_data(&mut self, hdt: HdtData) {
+        self.heading_true = hdt.heading;
+    }
+
+    fn merge_hdm_data(&mut self, hdm: HdmData) {
+        self.heading_magnetic = hdm.heading;
+    }
+
+    fn merge_hdg_data(&mut self, hdg: HdgData) {
+        self.heading_magnetic = hdg.magnetic_heading();
+        if let Some(heading_true) = hdg.true_heading() {
+            self.heading_true = Some(heading_true);
+        }
+    }
+
+    fn merge_hms_data(&mut self, hms: HmsData) {
+        self.heading_monitor.limit = hms.max_difference;
+    }
+
+    fn merge_hmr_data(&mut self, hmr: HmrData) {
+        let monitor = &mut self.heading_monitor;
+        monitor.limit = hmr.set_difference.or(monitor.limit);
+        monitor.difference = hmr.actual_difference;
+        monitor.alarm = match (hmr.within_limit, monitor.difference, monitor.limit) {
+            (Some(within_limit), _, _) => !within_limit,
+            (None, Some(difference), Some(limit)) => difference.abs() > limit,
+            _ => false,
+        };
+    }
+
+    fn merge_mtw_data(&mut self, mtw: MtwData) {
+        self.water_temperature = mtw.temperature;
+    }
+
+    fn merge_rot_data(&mut self, rot: RotData) {
+        if rot.valid {
+            self.rate_of_turn = rot.rate_of_turn;
+        }
+    }
+
+    fn merge_rsa_data(&mut self, rsa: RsaData) {
+        if rsa.starboard_valid {
+            self.rudder_angle = rsa.starboard;
+        }
+        if rsa.port_valid {
+            self.port_rudder_angle = rsa.port;
+        }
+    }
+
+    fn merge_rpm_data(&mut self, rpm: RpmData) {
+        if rpm.valid {
+            self.rpm.insert((rpm.source, rpm.number), rpm);
+        }
+    }
+
+    fn merge_vhw_data(&mut self, vhw: VhwData) {
+        if let Some(heading) = vhw.heading_true {
+            self.heading_true = Some(heading);
+        }
+        if let Some(heading) = vhw.heading_magnetic {
+            self.heading_magnetic = Some(heading);
+        }
+        self.speed_through_water = vhw.speed_through_water;
+        self.transverse_speed_through_water = None;
+    }
+
+    fn merge_vbw_data(&mut self, vbw: VbwData) {
+        if vbw.water_speed_valid {
+            self.speed_through_water = vbw.longitudinal_water_speed;
+            self.transverse_speed_through_water = vbw.transverse_water_speed;
+        }
+    }
+
+    fn merge_vlw_data(&mut self, vlw: VlwData) {
+        self.log = LogDistance {
+            total_water_distance: vlw.total_water_distance,
+            trip_water_distance: vlw.trip_water_distance,
+            total_ground_distance: vlw.total_ground_distance,
+            trip_ground_distance: vlw.trip_ground_distance,
+        };
+    }
+
+    fn merge_dbt_data(&mut self, dbt: DbtData) {
+        self.depth_below_transducer = dbt.depth;
+    }
+
+    fn merge_dbk_data(&mut self, dbk: DbkData) {
+        self.depth_below_keel = dbk.depth;
+    }
+
+    fn merge_dbs_data(&mut self, dbs: DbsData) {
+        self.depth_below_surface = dbs.depth;
+    }
+
+    fn merge_dpt_data(&mut self, dpt: DptData) {
+        self.depth_below_transducer = dpt.depth;
+        self.transducer_offset = dpt.offset;
+    }
+
+    fn merge_apparent_wind(&mut self, angle: Option<f32>, speed: Option<f32>) {
+        self.wind.apparent_angle = angle;
+        self.wind.apparent_speed = speed;
+        // Without a heading the vessel is assumed to move along its course
+        let heading = self.heading_true.or(self.true_course);
+        let course = self.true_course.or(self.heading_true);
+        if let (Some(angle), Some(speed), Some(sog), Some(heading), Some(course)) =
+            (angle, speed, self.speed_over_ground, heading, course)
+        {
+            let (true_direction, true_speed) = true_wind(angle, speed, heading, course, sog);
+            self.wind.true_direction = Some(true_direction);
+            self.wind.true_angle = Some((true_direction - heading).rem_euclid(360.));
+            self.wind.true_speed = Some(true_speed);
+        }
+    }
+
+    fn merge_mwv_data(&mut self, mwv: MwvData) {
+        if !mwv.valid {
+            return;
+        }
+        match mwv.reference {
+            Some(MwvReference::Relative) => {
+                self.merge_apparent_wind(mwv.wind_angle, mwv.wind_speed)
+            }
+            Some(MwvReference::Theoretical) => {
+                self.wind.true_angle = mwv.wind_angle;
+                self.wind.true_speed = mwv.wind_speed;
+                self.wind.true_direction = match (mwv.wind_angle, self.heading_true) {
+                    (Some(angle), Some(heading)) => Some((heading + angle).rem_euclid(360.)),
+                    _ => None,
+                };
+            }
+            None => {}
+        }
+    }
+
+    fn merge_mwd_data(&mut self, mwd: MwdData) {
+        self.wind.true_direction = mwd.wind_direction_true;
+        self.wind.true_speed = mwd.wind_speed;
+        self.wind.true_angle = match (mwd.wind_direction_true, self.heading_true) {
+            (Some(direction), Some(heading)) => Some((direction - heading).rem_euclid(360.)),
+            _ => None,
+        };
+    }
+
+    fn merge_vwr_data(&mut self, vwr: VwrData) {
+        let angle = vwr.wind_angle.map(|angle| angle.rem_euclid(360.));
+        self.merge_apparent_wind(angle, vwr.wind_speed);
+    }
+
+    fn merge_vpw_data(&mut self, vpw: VpwData) {
+        self.wind.speed_parallel_to_wind = vpw.speed;
+    }
+
     fn merge_txt_data(&mut self, txt: TxtData) {
         self.last_txt = Some(txt);
     }
 
+    fn merge_xdr_data(&mut self, xdr: XdrData) {
+        self.last_xdr = Some(xdr);
+    }
+
+    fn merge_zda_data(&mut self, zda: ZdaData) {
+        if let Some(date) = zda.utc_date() {
+            self.fix_date = Some(date);
+            self.last_zda_date = Some(date);
+        }
+    }
+
+    /// RMC and PSTI,030 only have a 2 digit year, once we got a ZDA sentence
+    /// we use its 4 digit year to pick the century instead of guessing it.
+    fn resolve_century(&self, date: NaiveDate) -> NaiveDate {
+        let zda_year = match self.last_zda_date {
+            Some(zda_date) => zda_date.year(),
+            None => return date,
+        };
+        let mut year = zda_year - zda_year.rem_euclid(100) + date.year().rem_euclid(100);
+        if year - zda_year > 50 {
+            year -= 100;
+        } else if zda_year - year > 50 {
+            year += 100;
+        }
+        date.with_year(year).unwrap_or(date)
+    }
+
     /// Parse any NMEA sentence and stores the result. The type of sentence
-    /// is returnd if implemented and valid.
+    /// is returnd if implemented and valid, `SentenceType::Proprietary` for
+    /// proprietary sentences handled by a registered decoder.
     pub fn parse(&mut self, s: &'a str) -> Result<SentenceType, NmeaError<'a>> {
         match parse(s.as_bytes())? {
             ParseResult::VTG(vtg) => {
@@ -240,6 +1098,10 @@ impl<'a> Nmea {
                 self.merge_gga_data(gga);
                 Ok(SentenceType::GGA)
             }
+            ParseResult::GBS(gbs) => {
+                self.merge_gbs_data(gbs);
+                Ok(SentenceType::GBS)
+            }
             ParseResult::GSV(gsv) => {
                 self.merge_gsv_data(gsv)?;
                 Ok(SentenceType::GSV)
@@ -252,25 +1114,274 @@ impl<'a> Nmea {
                 self.merge_gsa_data(gsa);
                 Ok(SentenceType::GSA)
             }
+            ParseResult::GST(gst) => {
+                self.merge_gst_data(gst);
+                Ok(SentenceType::GST)
+            }
             ParseResult::GLL(gll) => {
                 self.merge_gll_data(gll);
                 Ok(SentenceType::GLL)
             }
+            ParseResult::GNS(gns) => {
+                self.merge_gns_data(gns);
+                Ok(SentenceType::GNS)
+            }
+            ParseResult::HDT(hdt) => {
+                self.merge_hdt_data(hdt);
+                Ok(SentenceType::HDT)
+            }
+            ParseResult::HDM(hdm) => {
+                self.merge_hdm_data(hdm);
+                Ok(SentenceType::HDM)
+            }
+            ParseResult::HDG(hdg) => {
+                self.merge_hdg_data(hdg);
+                Ok(SentenceType::HDG)
+            }
+            ParseResult::HMS(hms) => {
+                self.merge_hms_data(hms);
+                Ok(SentenceType::HMS)
+            }
+            ParseResult::HMR(hmr) => {
+                self.merge_hmr_data(hmr);
+                Ok(SentenceType::HMR)
+            }
+            ParseResult::MTW(mtw) => {
+                self.merge_mtw_data(mtw);
+                Ok(SentenceType::MTW)
+            }
+            ParseResult::VHW(vhw) => {
+                self.merge_vhw_data(vhw);
+                Ok(SentenceType::VHW)
+            }
+            ParseResult::ROT(rot) => {
+                self.merge_rot_data(rot);
+                Ok(SentenceType::ROT)
+            }
+            ParseResult::RSA(rsa) => {
+                self.merge_rsa_data(rsa);
+                Ok(SentenceType::RSA)
+            }
+            ParseResult::RPM(rpm) => {
+                self.merge_rpm_data(rpm);
+                Ok(SentenceType::RPM)
+            }
+            ParseResult::VBW(vbw) => {
+                self.merge_vbw_data(vbw);
+                Ok(SentenceType::VBW)
+            }
+            ParseResult::VLW(vlw) => {
+                self.merge_vlw_data(vlw);
+                Ok(SentenceType::VLW)
+            }
+            ParseResult::DBT(dbt) => {
+                self.merge_dbt_data(dbt);
+                Ok(SentenceType::DBT)
+            }
+            ParseResult::DBK(dbk) => {
+                self.merge_dbk_data(dbk);
+                Ok(SentenceType::DBK)
+            }
+            ParseResult::DBS(dbs) => {
+                self.merge_dbs_data(dbs);
+                Ok(SentenceType::DBS)
+            }
+            ParseResult::DPT(dpt) => {
+                self.merge_dpt_data(dpt);
+                Ok(SentenceType::DPT)
+            }
+            ParseResult::MWV(mwv) => {
+                self.merge_mwv_data(mwv);
+                Ok(SentenceType::MWV)
+            }
+            ParseResult::MWD(mwd) => {
+                self.merge_mwd_data(mwd);
+                Ok(SentenceType::MWD)
+            }
+            ParseResult::VWR(vwr) => {
+                self.merge_vwr_data(vwr);
+                Ok(SentenceType::VWR)
+            }
+            ParseResult::VPW(vpw) => {
+                self.merge_vpw_data(vpw);
+                Ok(SentenceType::VPW)
+            }
             ParseResult::TXT(txt) => {
                 self.merge_txt_data(txt);
                 Ok(SentenceType::TXT)
             }
-            ParseResult::BWC(_) => Err(NmeaError::Unsupported(SentenceType::BWC)),
+            ParseResult::XDR(xdr) => {
+                self.merge_xdr_data(xdr);
+                Ok(SentenceType::XDR)
+            }
+            ParseResult::DTM(dtm) => {
+                self.merge_dtm_data(dtm);
+                Ok(SentenceType::DTM)
+            }
+            ParseResult::GRS(grs) => {
+                self.merge_grs_data(grs);
+                Ok(SentenceType::GRS)
+            }
+            ParseResult::ZDA(zda) => {
+                self.merge_zda_data(zda);
+                Ok(SentenceType::ZDA)
+            }
+            ParseResult::BWC(bwc) => {
+                self.merge_bwc_data(bwc);
+                Ok(SentenceType::BWC)
+            }
+            ParseResult::RMB(rmb) => {
+                self.merge_rmb_data(rmb);
+                Ok(SentenceType::RMB)
+            }
+            ParseResult::APB(apb) => {
+                self.merge_apb_data(apb);
+                Ok(SentenceType::APB)
+            }
+            ParseResult::BWR(bwr) => {
+                self.merge_bwc_data(bwr);
+                Ok(SentenceType::BWR)
+            }
+            ParseResult::BOD(bod) => {
+                self.merge_bod_data(bod);
+                Ok(SentenceType::BOD)
+            }
+            ParseResult::WCV(wcv) => {
+                self.merge_wcv_data(wcv);
+                Ok(SentenceType::WCV)
+            }
+            ParseResult::XTE(xte) => {
+                self.merge_xte_data(xte);
+                Ok(SentenceType::XTE)
+            }
+            ParseResult::AAM(aam) => {
+                self.merge_aam_data(aam);
+                Ok(SentenceType::AAM)
+            }
+            ParseResult::ZTG(ztg) => {
+                self.merge_ztg_data(ztg);
+                Ok(SentenceType::ZTG)
+            }
+            // Waypoint to waypoint data is not about the active leg
+            ParseResult::BWW(_) => Ok(SentenceType::BWW),
+            ParseResult::WNC(_) => Ok(SentenceType::WNC),
+            // Times from the origin and to a variable point are not tracked
+            ParseResult::ZFO(_) => Ok(SentenceType::ZFO),
+            ParseResult::ZDL(_) => Ok(SentenceType::ZDL),
+            ParseResult::ALM(alm) => {
+                self.merge_alm_data(alm);
+                Ok(SentenceType::ALM)
+            }
+            ParseResult::WPL(wpl) => {
+                self.merge_wpl_data(wpl);
+                Ok(SentenceType::WPL)
+            }
+            ParseResult::TTM(ttm) => {
+                self.merge_ttm_data(ttm);
+                Ok(SentenceType::TTM)
+            }
+            ParseResult::TLL(tll) => {
+                self.merge_tll_data(tll);
+                Ok(SentenceType::TLL)
+            }
+            ParseResult::VDM(vdm) => {
+                self.merge_vdm_data(vdm)?;
+                Ok(SentenceType::VDM)
+            }
+            ParseResult::VDO(vdo) => {
+                self.merge_vdm_data(vdo)?;
+                Ok(SentenceType::VDO)
+            }
+            ParseResult::RTE(rte) => {
+                self.merge_rte_data(rte);
+                Ok(SentenceType::RTE)
+            }
+            ParseResult::PUBX(PubxData::Position(pubx)) => {
+                self.merge_pubx_position_data(pubx);
+                Ok(SentenceType::PUBX)
+            }
+            ParseResult::PUBX(_) => Ok(SentenceType::PUBX),
+            ParseResult::PMTK(pmtk) => {
+                self.pmtk_response = Some(pmtk);
+                Ok(SentenceType::PMTK)
+            }
+            ParseResult::PSTI(psti) => {
+                self.merge_psti_data(psti);
+                Ok(SentenceType::PSTI)
+            }
+            ParseResult::PGRME(pgrme) => {
+                self.merge_pgrme_data(pgrme);
+                Ok(SentenceType::PGRME)
+            }
+            ParseResult::PGRMM(pgrmm) => {
+                self.merge_pgrmm_data(pgrmm);
+                Ok(SentenceType::PGRMM)
+            }
+            ParseResult::PGRMZ(pgrmz) => {
+                self.merge_pgrmz_data(pgrmz);
+                Ok(SentenceType::PGRMZ)
+            }
+            ParseResult::Proprietary(sentence) => {
+                self.decode_proprietary(&sentence)?;
+                Ok(SentenceType::Proprietary)
+            }
             ParseResult::Unsupported(sentence_type) => Err(NmeaError::Unsupported(sentence_type)),
         }
     }
 
+    fn decode_proprietary(
+        &mut self,
+        sentence: &ProprietarySentence,
+    ) -> Result<(), NmeaError<'static>> {
+        match self.proprietary_decoders.get(&sentence.manufacturer) {
+            Some(decoder) => decoder(self, sentence),
+            None => Err(NmeaError::Unsupported(SentenceType::Proprietary)),
+        }
+    }
+
     fn new_tick(&mut self) {
         let old = mem::take(self);
         self.satellites_scan = old.satellites_scan;
         self.satellites = old.satellites;
+        self.satellite_residuals = old.satellite_residuals;
         self.required_sentences_for_nav = old.required_sentences_for_nav;
         self.last_fix_time = old.last_fix_time;
+        self.last_zda_date = old.last_zda_date;
+        // Heading comes from a gyro or compass, not from the GNSS fix
+        self.heading_true = old.heading_true;
+        self.heading_magnetic = old.heading_magnetic;
+        self.attitude = old.attitude;
+        self.baseline = old.baseline;
+        // Same for the log, the echo sounder and the steering
+        self.rate_of_turn = old.rate_of_turn;
+        self.rudder_angle = old.rudder_angle;
+        self.port_rudder_angle = old.port_rudder_angle;
+        self.rpm = old.rpm;
+        self.speed_through_water = old.speed_through_water;
+        self.transverse_speed_through_water = old.transverse_speed_through_water;
+        self.log = old.log;
+        self.depth_below_transducer = old.depth_below_transducer;
+        self.depth_below_keel = old.depth_below_keel;
+        self.depth_below_surface = old.depth_below_surface;
+        self.transducer_offset = old.transducer_offset;
+        self.navigation = old.navigation;
+        self.wind = old.wind;
+        self.heading_monitor = old.heading_monitor;
+        self.water_temperature = old.water_temperature;
+        self.waypoints = old.waypoints;
+        self.route_scan = old.route_scan;
+        self.route = old.route;
+        self.ais_fragments = old.ais_fragments;
+        self.ais_message = old.ais_message;
+        self.pmtk_response = old.pmtk_response;
+        self.targets = old.targets;
+        self.almanac = old.almanac;
+        self.almanac_scan = old.almanac_scan;
+        self.almanac_complete = old.almanac_complete;
+        self.last_xdr = old.last_xdr;
+        self.datum = old.datum;
+        self.map_datum = old.map_datum;
+        self.proprietary_decoders = old.proprietary_decoders;
     }
 
     fn clear_position_info(&mut self) {
@@ -284,6 +1395,14 @@ impl<'a> Nmea {
                 self.merge_gsa_data(gsa);
                 return Ok(FixType::Invalid);
             }
+            ParseResult::GST(gst) => {
+                self.merge_gst_data(gst);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::GBS(gbs) => {
+                self.merge_gbs_data(gbs);
+                return Ok(FixType::Invalid);
+            }
             ParseResult::GSV(gsv_data) => {
                 self.merge_gsv_data(gsv_data)?;
                 return Ok(FixType::Invalid);
@@ -349,15 +1468,271 @@ impl<'a> Nmea {
                 self.merge_gga_data(gga_data);
                 self.sentences_for_this_time.insert(SentenceType::GGA);
             }
+            ParseResult::GNS(gns_data) => {
+                if gns_data.fix_type() == FixType::Invalid {
+                    self.clear_position_info();
+                    return Ok(FixType::Invalid);
+                }
+                match (self.last_fix_time, gns_data.fix_time) {
+                    (Some(ref last_fix_time), Some(ref gns_fix_time)) => {
+                        if last_fix_time != gns_fix_time {
+                            self.new_tick();
+                            self.last_fix_time = Some(*gns_fix_time);
+                        }
+                    }
+                    (None, Some(ref gns_fix_time)) => self.last_fix_time = Some(*gns_fix_time),
+                    (Some(_), None) | (None, None) => {
+                        self.clear_position_info();
+                        return Ok(FixType::Invalid);
+                    }
+                }
+                self.merge_gns_data(gns_data);
+                self.sentences_for_this_time.insert(SentenceType::GNS);
+            }
+            ParseResult::PUBX(PubxData::Position(pubx)) => {
+                if pubx.fix_type() == FixType::Invalid {
+                    self.clear_position_info();
+                    return Ok(FixType::Invalid);
+                }
+                match self.last_fix_time {
+                    Some(ref last_fix_time) => {
+                        if *last_fix_time != pubx.fix_time {
+                            self.new_tick();
+                            self.last_fix_time = Some(pubx.fix_time);
+                        }
+                    }
+                    None => self.last_fix_time = Some(pubx.fix_time),
+                }
+                self.merge_pubx_position_data(pubx);
+                self.sentences_for_this_time.insert(SentenceType::PUBX);
+            }
+            ParseResult::PUBX(_) => {
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::PSTI(PstiData::RtkPosition(psti)) => {
+                if psti.fix_type() == FixType::Invalid {
+                    self.clear_position_info();
+                    return Ok(FixType::Invalid);
+                }
+                match (self.last_fix_time, psti.fix_time) {
+                    (Some(ref last_fix_time), Some(ref psti_fix_time)) => {
+                        if last_fix_time != psti_fix_time {
+                            self.new_tick();
+                            self.last_fix_time = Some(*psti_fix_time);
+                        }
+                    }
+                    (None, Some(ref psti_fix_time)) => self.last_fix_time = Some(*psti_fix_time),
+                    (Some(_), None) | (None, None) => {
+                        self.clear_position_info();
+                        return Ok(FixType::Invalid);
+                    }
+                }
+                self.merge_psti_rtk_position_data(psti);
+                self.sentences_for_this_time.insert(SentenceType::PSTI);
+            }
+            ParseResult::PSTI(psti) => {
+                self.merge_psti_data(psti);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::PMTK(pmtk) => {
+                self.pmtk_response = Some(pmtk);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::PGRME(pgrme) => {
+                self.merge_pgrme_data(pgrme);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::PGRMM(pgrmm) => {
+                self.merge_pgrmm_data(pgrmm);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::PGRMZ(pgrmz) => {
+                self.merge_pgrmz_data(pgrmz);
+                return Ok(FixType::Invalid);
+            }
             ParseResult::GLL(gll_data) => {
                 self.merge_gll_data(gll_data);
                 return Ok(FixType::Invalid);
             }
+            ParseResult::HDT(hdt) => {
+                self.merge_hdt_data(hdt);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::HDM(hdm) => {
+                self.merge_hdm_data(hdm);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::HDG(hdg) => {
+                self.merge_hdg_data(hdg);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::HMS(hms) => {
+                self.merge_hms_data(hms);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::HMR(hmr) => {
+                self.merge_hmr_data(hmr);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::MTW(mtw) => {
+                self.merge_mtw_data(mtw);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::VHW(vhw) => {
+                self.merge_vhw_data(vhw);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::ROT(rot) => {
+                self.merge_rot_data(rot);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::RSA(rsa) => {
+                self.merge_rsa_data(rsa);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::RPM(rpm) => {
+                self.merge_rpm_data(rpm);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::VBW(vbw) => {
+                self.merge_vbw_data(vbw);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::VLW(vlw) => {
+                self.merge_vlw_data(vlw);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::DBT(dbt) => {
+                self.merge_dbt_data(dbt);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::DBK(dbk) => {
+                self.merge_dbk_data(dbk);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::DBS(dbs) => {
+                self.merge_dbs_data(dbs);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::DPT(dpt) => {
+                self.merge_dpt_data(dpt);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::MWV(mwv) => {
+                self.merge_mwv_data(mwv);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::MWD(mwd) => {
+                self.merge_mwd_data(mwd);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::VWR(vwr) => {
+                self.merge_vwr_data(vwr);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::VPW(vpw) => {
+                self.merge_vpw_data(vpw);
+                return Ok(FixType::Invalid);
+            }
             ParseResult::TXT(txt_data) => {
                 self.merge_txt_data(txt_data);
                 return Ok(FixType::Invalid);
             }
-            ParseResult::BWC(_) => return Ok(FixType::Invalid),
+            ParseResult::XDR(xdr_data) => {
+                self.merge_xdr_data(xdr_data);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::DTM(dtm) => {
+                self.merge_dtm_data(dtm);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::GRS(grs) => {
+                self.merge_grs_data(grs);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::ZDA(zda_data) => {
+                self.merge_zda_data(zda_data);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::BWC(bwc) => {
+                self.merge_bwc_data(bwc);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::RMB(rmb) => {
+                self.merge_rmb_data(rmb);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::APB(apb) => {
+                self.merge_apb_data(apb);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::BWR(bwr) => {
+                self.merge_bwc_data(bwr);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::BOD(bod) => {
+                self.merge_bod_data(bod);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::WCV(wcv) => {
+                self.merge_wcv_data(wcv);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::XTE(xte) => {
+                self.merge_xte_data(xte);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::AAM(aam) => {
+                self.merge_aam_data(aam);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::ZTG(ztg) => {
+                self.merge_ztg_data(ztg);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::BWW(_)
+            | ParseResult::WNC(_)
+            | ParseResult::ZFO(_)
+            | ParseResult::ZDL(_) => {
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::ALM(alm) => {
+                self.merge_alm_data(alm);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::WPL(wpl) => {
+                self.merge_wpl_data(wpl);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::TTM(ttm) => {
+                self.merge_ttm_data(ttm);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::TLL(tll) => {
+                self.merge_tll_data(tll);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::VDM(vdm) => {
+                self.merge_vdm_data(vdm)?;
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::VDO(vdo) => {
+                self.merge_vdm_data(vdo)?;
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::RTE(rte) => {
+                self.merge_rte_data(rte);
+                return Ok(FixType::Invalid);
+            }
+            ParseResult::Proprietary(sentence) => {
+                if self
+                    .proprietary_decoders
+                    .contains_key(&sentence.manufacturer)
+                {
+                    self.decode_proprietary(&sentence)?;
+                }
+                return Ok(FixType::Invalid);
+            }
             ParseResult::Unsupported(_) => {
                 return Ok(FixType::Invalid);
             }
@@ -378,6 +1753,130 @@ impl<'a> Nmea {
     pub fn last_txt(&self) -> Option<&TxtData> {
         self.last_txt.as_ref()
     }
+
+    /// Returns the transducer measurements of the last XDR sentence
+    pub fn last_xdr(&self) -> Option<&XdrData> {
+        self.last_xdr.as_ref()
+    }
+
+    /// Returns the navigation state towards the active waypoint
+    pub fn navigation(&self) -> &NavigationState {
+        &self.navigation
+    }
+
+    /// Returns the last time to go to the active waypoint.
+    /// None if not available.
+    pub fn time_to_go(&self) -> Option<Duration> {
+        self.navigation.time_to_go
+    }
+
+    /// Returns the state of the heading monitor, merged from HMS and HMR
+    pub fn heading_monitor(&self) -> &HeadingMonitorState {
+        &self.heading_monitor
+    }
+
+    /// Returns the last wind data, with the true wind computed from the
+    /// apparent wind when it is not reported directly
+    pub fn wind(&self) -> &WindState {
+        &self.wind
+    }
+
+    /// Takes the last AIS message completed by VDM or VDO sentences, every
+    /// message is returned once
+    pub fn take_ais_message(&mut self) -> Option<AisMessage> {
+        self.ais_message.take()
+    }
+
+    /// Takes the last acknowledgement or query response of a MediaTek
+    /// receiver, every response is returned once
+    pub fn take_pmtk_response(&mut self) -> Option<PmtkData> {
+        self.pmtk_response.take()
+    }
+
+    /// Returns the almanacs received in ALM sentences, ordered by PRN
+    pub fn almanacs(&self) -> impl Iterator<Item = &AlmData> {
+        self.almanac.values()
+    }
+
+    /// Returns the almanac of the satellite with the given PRN
+    pub fn almanac(&self, prn: u8) -> Option<&AlmData> {
+        self.almanac.get(&prn)
+    }
+
+    /// Returns true once all the sentences of an almanac dump were received
+    /// in order, false while a new dump is in progress
+    pub fn is_almanac_complete(&self) -> bool {
+        self.almanac_complete
+    }
+
+    /// Returns the radar targets reported by TTM and TLL, ordered by target
+    /// number. Lost targets and targets without an update for a minute are
+    /// dropped.
+    pub fn targets(&self) -> impl Iterator<Item = &Target> {
+        self.targets.values()
+    }
+
+    /// Returns the radar target with the given number
+    pub fn target(&self, number: u8) -> Option<&Target> {
+        self.targets.get(&number)
+    }
+
+    /// Registers a decoder for the proprietary sentences of a manufacturer,
+    /// given by its three letter mnemonic, e.g. `"UBX"` for `$PUBX`. For
+    /// the manufacturers parsed by this crate the decoder gets the messages
+    /// which are not parsed, e.g. `$PUBX,41`. A decoder registered for the
+    /// same manufacturer before is replaced.
+    /// Returns `NmeaError::InvalidManufacturer` when the mnemonic is not
+    /// three ASCII characters.
+    ///
+    /// Without a decoder, `parse` returns
+    /// `NmeaError::Unsupported(SentenceType::Proprietary)` for
+    /// proprietary sentences and `parse_for_fix` ignores them.
+    pub fn register_proprietary_decoder(
+        &mut self,
+        manufacturer: &str,
+        decoder: ProprietaryDecoder,
+    ) -> Result<(), NmeaError<'static>> {
+        if manufacturer.len() != 3 || !manufacturer.is_ascii() {
+            return Err(NmeaError::InvalidManufacturer);
+        }
+        let manufacturer =
+            ArrayString::from(manufacturer).map_err(|_| NmeaError::InvalidManufacturer)?;
+        self.proprietary_decoders.insert(manufacturer, decoder);
+        Ok(())
+    }
+
+    /// Returns the position of a waypoint received in a WPL sentence
+    pub fn waypoint(&self, waypoint_id: &str) -> Option<(f64, f64)> {
+        self.waypoints.get(waypoint_id).copied()
+    }
+
+    /// Returns the last complete route received in RTE sentences, with the
+    /// waypoint positions known from WPL sentences filled in.
+    pub fn route(&self) -> Option<Route> {
+        let mut route = self.route.clone()?;
+        for waypoint in &mut route.waypoints {
+            if let Some((lat, lon)) = self.waypoint(&waypoint.id) {
+                waypoint.latitude = Some(lat);
+                waypoint.longitude = Some(lon);
+            }
+        }
+        Some(route)
+    }
+
+    pub fn last_gbs(&self) -> Option<&GbsData> {
+        self.last_gbs.as_ref()
+    }
+
+    /// Returns the RAIM integrity status of the current fix. None if no GBS
+    /// sentence was received for the epoch of the current fix.
+    pub fn integrity_status(&self) -> Option<IntegrityStatus> {
+        let gbs = self.last_gbs.as_ref()?;
+        match gbs.fix_time {
+            Some(gbs_fix_time) if Some(gbs_fix_time) != self.fix_time => None,
+            _ => Some(gbs.integrity_status()),
+        }
+    }
 }
 
 impl fmt::Display for Nmea {
@@ -402,6 +1901,135 @@ impl fmt::Display for Nmea {
     }
 }
 
+/// Navigation towards the active waypoint, merged from BWC, BWR, RMB, APB,
+/// BOD, XTE, WCV, AAM and ZTG.
+///
+/// Distances are in nautical miles, speeds in knots and bearings in degrees.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NavigationState {
+    pub origin_waypoint_id: Option<ArrayString<[u8; 64]>>,
+    pub destination_waypoint_id: Option<ArrayString<[u8; 64]>>,
+    pub destination_latitude: Option<f64>,
+    pub destination_longitude: Option<f64>,
+    pub cross_track_error: Option<f32>,
+    pub steer_direction: Option<SteerDirection>,
+    pub bearing_to_destination_true: Option<f32>,
+    pub bearing_to_destination_magnetic: Option<f32>,
+    pub distance_to_destination: Option<f32>,
+    pub closing_velocity: Option<f32>,
+    pub heading_to_steer_true: Option<f32>,
+    pub heading_to_steer_magnetic: Option<f32>,
+    pub arrived: Option<bool>,
+    pub time_to_go: Option<Duration>,
+}
+
+impl NavigationState {
+    /// Forget everything about the previous waypoint if the destination changed
+    fn set_destination(&mut self, waypoint_id: Option<ArrayString<[u8; 64]>>) {
+        if waypoint_id.is_some() && waypoint_id != self.destination_waypoint_id {
+            *self = NavigationState {
+                destination_waypoint_id: waypoint_id,
+                ..NavigationState::default()
+            };
+        }
+    }
+}
+
+/// Distances travelled by the vessel from VLW, nautical miles
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LogDistance {
+    pub total_water_distance: Option<f32>,
+    pub trip_water_distance: Option<f32>,
+    pub total_ground_distance: Option<f32>,
+    pub trip_ground_distance: Option<f32>,
+}
+
+/// Wind merged from MWV, MWD, VWR and VPW.
+///
+/// Speeds are in knots. Angles are in degrees clockwise from the bow and
+/// directions in degrees from true north, both for where the wind blows from.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WindState {
+    pub apparent_angle: Option<f32>,
+    pub apparent_speed: Option<f32>,
+    pub true_angle: Option<f32>,
+    pub true_speed: Option<f32>,
+    pub true_direction: Option<f32>,
+    pub speed_parallel_to_wind: Option<f32>,
+}
+
+/// Heading monitor comparing two heading sensors, merged from HMS and HMR.
+///
+/// Differences are in degrees. The alarm is raised when the difference
+/// between the sensors exceeds the limit.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct HeadingMonitorState {
+    pub limit: Option<f32>,
+    pub difference: Option<f32>,
+    pub alarm: bool,
+}
+
+/// Compute the true wind direction and speed from the apparent wind by adding
+/// back the velocity of the vessel, the apparent angle is relative to the
+/// heading and the vessel moves along the course.
+fn true_wind(
+    apparent_angle: f32,
+    apparent_speed: f32,
+    heading: f32,
+    course: f32,
+    speed_over_ground: f32,
+) -> (f32, f32) {
+    let apparent_direction = (heading + apparent_angle).to_radians();
+    let course = course.to_radians();
+    // East and north components of the air movement
+    let east = -apparent_speed * apparent_direction.sin() + speed_over_ground * course.sin();
+    let north = -apparent_speed * apparent_direction.cos() + speed_over_ground * course.cos();
+    let direction = (-east).atan2(-north).to_degrees().rem_euclid(360.);
+    (direction, east.hypot(north))
+}
+
+/// Seconds after which a target that is not reported any more is dropped
+const TARGET_TIMEOUT_SECS: i64 = 60;
+
+/// Radar target merged from TTM and TLL.
+///
+/// Distances are in nautical miles, speeds in knots and angles in degrees.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Target {
+    pub number: u8,
+    pub name: Option<ArrayString<[u8; 64]>>,
+    pub status: Option<TargetStatus>,
+    pub distance: Option<f32>,
+    pub bearing: Option<f32>,
+    pub bearing_reference: Option<TargetReference>,
+    pub speed: Option<f32>,
+    pub course: Option<f32>,
+    pub course_reference: Option<TargetReference>,
+    pub cpa_distance: Option<f32>,
+    /// Time to CPA in minutes
+    pub time_to_cpa: Option<f32>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub last_update: Option<NaiveTime>,
+}
+
+/// Route assembled from RTE sentences
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    pub route_id: Option<ArrayString<[u8; 64]>>,
+    pub mode: RteMode,
+    pub waypoints: Vec<RouteWaypoint>,
+}
+
+/// Waypoint of a route, the position is None if no WPL sentence was
+/// received for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteWaypoint {
+    pub id: ArrayString<[u8; 64]>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
 #[derive(Clone, PartialEq)]
 /// Satellite information
 pub struct Satellite {
@@ -410,6 +2038,7 @@ pub struct Satellite {
     elevation: Option<f32>,
     azimuth: Option<f32>,
     snr: Option<f32>,
+    residual: Option<f32>,
 }
 
 impl Satellite {
@@ -432,6 +2061,12 @@ impl Satellite {
     pub fn snr(&self) -> Option<f32> {
         self.snr
     }
+
+    /// Range residual in meters from the last GRS, None if the satellite was
+    /// not used in the fix.
+    pub fn residual(&self) -> Option<f32> {
+        self.residual
+    }
 }
 
 impl fmt::Display for Satellite {
@@ -474,6 +2109,8 @@ macro_rules! define_sentence_type_enum {
         #[repr(C)]
         pub enum $Name {
             $($Variant),*,
+            /// Proprietary sentence handled by a registered decoder
+            Proprietary,
             None
         }
 
@@ -498,8 +2135,8 @@ macro_rules! define_sentence_type_enum {
                 }
             }
 
-            fn to_mask_value(&self) -> u128 {
-                1 << *self as u32
+            fn to_mask_value(self) -> u128 {
+                1 << self as u32
             }
         }
     }
@@ -529,6 +2166,7 @@ define_sentence_type_enum!(
     ///                      VTG | WCV | WNC | WPL | XDR | XTE | XTR |
     /// Wind: MWV | VPW | VWR |
     /// Date and Time: GDT | ZDA | ZFO | ZTG |
+    /// Proprietary: PGRME | PGRMM | PGRMZ | PMTK | PSTI | PUBX |
     enum SentenceType {
         AAM,
         ABK,
@@ -592,6 +2230,12 @@ define_sentence_type_enum!(
         MWV,
         OLN,
         OSD,
+        PGRME,
+        PGRMM,
+        PGRMZ,
+        PMTK,
+        PSTI,
+        PUBX,
         ROO,
         RMA,
         RMB,
@@ -639,7 +2283,7 @@ pub struct SentenceMask {
 }
 
 impl SentenceMask {
-    fn contains(&self, sentence_type: &SentenceType) -> bool {
+    pub fn contains(&self, sentence_type: &SentenceType) -> bool {
         sentence_type.to_mask_value() & self.mask != 0
     }
 
@@ -647,7 +2291,7 @@ impl SentenceMask {
         (mask.mask | self.mask) == mask.mask
     }
 
-    fn insert(&mut self, sentence_type: SentenceType) {
+    pub fn insert(&mut self, sentence_type: SentenceType) {
         self.mask |= sentence_type.to_mask_value()
     }
 }
@@ -690,6 +2334,8 @@ pub enum GnssType {
     Galileo,
     Gps,
     Glonass,
+    Qzss,
+    NavIC,
 }
 
 impl fmt::Display for GnssType {
@@ -699,6 +2345,8 @@ impl fmt::Display for GnssType {
             GnssType::Galileo => write!(f, "Galileo"),
             GnssType::Gps => write!(f, "GPS"),
             GnssType::Glonass => write!(f, "GLONASS"),
+            GnssType::Qzss => write!(f, "QZSS"),
+            GnssType::NavIC => write!(f, "NavIC"),
         }
     }
 }
@@ -724,6 +2372,7 @@ impl From<char> for FixType {
 mod tests {
     use super::parse::checksum;
     use super::*;
+    use approx::assert_relative_eq;
     use quickcheck::QuickCheck;
 
     fn check_parsing_lat_lon_in_gga(lat: f64, lon: f64) -> bool {
@@ -767,12 +2416,9 @@ mod tests {
         use crate::parse::checksum;
         let valid = "$GNGSA,A,1,,,,,,,,,,,,,99.99,99.99,99.99*2E";
         let invalid = "$GNZDA,165118.00,13,05,2016,00,00*71";
-        assert_eq!(
-            checksum((&valid[1..valid.len() - 3]).as_bytes().iter()),
-            0x2E
-        );
+        assert_eq!(checksum(valid.as_bytes()[1..valid.len() - 3].iter()), 0x2E);
         assert_ne!(
-            checksum((&invalid[1..invalid.len() - 3]).as_bytes().iter()),
+            checksum(invalid.as_bytes()[1..invalid.len() - 3].iter()),
             0x71
         );
     }
@@ -830,9 +2476,11 @@ mod tests {
     #[test]
     fn test_gga_invalid() {
         let mut nmea = Nmea::new();
-        nmea.parse("$GPGGA,092750.000,5321.6802,S,00630.3372,E,0,8,1.03,61.7,M,55.2,M,,*7B")
-            .unwrap_err();
-        assert_eq!(nmea.fix_type(), None);
+        assert_eq!(
+            nmea.parse("$GPGGA,092750.000,5321.6802,S,00630.3372,E,0,8,1.03,61.7,M,55.2,M,,*78"),
+            Ok(SentenceType::GGA)
+        );
+        assert_eq!(nmea.fix_type(), Some(FixType::Invalid));
     }
 
     #[test]
@@ -968,92 +2616,92 @@ mod tests {
                 (
                     "$GPRMC,123308.2,A,5521.76474,N,03731.92553,E,000.48,071.9,090317,010.2,E,A*3B",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 200)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 200).unwrap()),
                 ),
                 (
                     "$GPGGA,123308.2,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*52",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 200)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 200).unwrap()),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.48,N,0000.88,K,A*10",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 200)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 200).unwrap()),
                 ),
                 (
                     "$GPRMC,123308.3,A,5521.76474,N,03731.92553,E,000.51,071.9,090317,010.2,E,A*32",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 300).unwrap()),
                 ),
                 (
                     "$GPGGA,123308.3,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*53",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 300).unwrap()),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.51,N,0000.94,K,A*15",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 300).unwrap()),
                 ),
                 (
                     "$GPRMC,123308.4,A,5521.76474,N,03731.92553,E,000.54,071.9,090317,010.2,E,A*30",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 400)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 400).unwrap()),
                 ),
                 (
                     "$GPGGA,123308.4,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*54",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 400)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 400).unwrap()),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.54,N,0001.00,K,A*1C",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 400)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 400).unwrap()),
                 ),
                 (
                     "$GPRMC,123308.5,A,5521.76474,N,03731.92553,E,000.57,071.9,090317,010.2,E,A*32",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 500)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 500).unwrap()),
                 ),
                 (
                     "$GPGGA,123308.5,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*55",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 500)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 500).unwrap()),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.57,N,0001.05,K,A*1A",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 500)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 500).unwrap()),
                 ),
                 (
                     "$GPRMC,123308.6,A,5521.76474,N,03731.92553,E,000.58,071.9,090317,010.2,E,A*3E",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 600)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 600).unwrap()),
                 ),
                 (
                     "$GPGGA,123308.6,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*56",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 600)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 600).unwrap()),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.58,N,0001.08,K,A*18",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 600)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 600).unwrap()),
                 ),
                 (
                     "$GPRMC,123308.7,A,5521.76474,N,03731.92553,E,000.59,071.9,090317,010.2,E,A*3E",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 700)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 700).unwrap()),
                 ),
                 (
                     "$GPGGA,123308.7,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*57",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 700)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 700).unwrap()),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.59,N,0001.09,K,A*18",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 700)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 700).unwrap()),
                 ),
             ];
 
@@ -1071,17 +2719,17 @@ mod tests {
                 (
                     "$GPRMC,123308.2,A,5521.76474,N,03731.92553,E,000.48,071.9,090317,010.2,E,A*3B",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 200)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 200).unwrap()),
                 ),
                 (
                     "$GPRMC,123308.3,A,5521.76474,N,03731.92553,E,000.51,071.9,090317,010.2,E,A*32",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 300).unwrap()),
                 ),
                 (
                     "$GPGGA,123308.3,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*53",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    Some(NaiveTime::from_hms_milli_opt(12, 33, 8, 300).unwrap()),
                 ),
             ];
 
@@ -1093,6 +2741,775 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_gst_accuracy() {
+        let mut nmea = Nmea::new();
+        assert_eq!(nmea.horizontal_accuracy(), None);
+        nmea.parse("$GPGST,182141.000,15.5,15.3,7.2,21.8,0.9,0.5,0.8*54")
+            .unwrap();
+        assert_eq!(nmea.latitude_error, Some(0.9));
+        assert_eq!(nmea.longitude_error, Some(0.5));
+        assert!((nmea.horizontal_accuracy().unwrap() - 1.029_563).abs() < 1e-5);
+        assert_eq!(nmea.vertical_accuracy(), Some(0.8));
+    }
+
+    #[test]
+    fn test_gbs_integrity_status() {
+        let mut nmea = Nmea::new();
+        assert_eq!(nmea.integrity_status(), None);
+        nmea.parse("$GPGGA,015509.00,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*47")
+            .unwrap();
+        nmea.parse("$GPGBS,015509.00,-0.031,-0.186,0.219,19,0.000,-0.354,6.972*4D")
+            .unwrap();
+        assert_eq!(
+            nmea.integrity_status(),
+            Some(IntegrityStatus::FaultySatellite(19))
+        );
+
+        // GBS of the previous epoch does not apply to the new fix
+        nmea.parse("$GPGGA,015510.00,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*4F")
+            .unwrap();
+        assert_eq!(nmea.integrity_status(), None);
+        nmea.parse("$GPGBS,015510.00,,,,,,,*6F").unwrap();
+        assert_eq!(nmea.integrity_status(), Some(IntegrityStatus::Ok));
+    }
+
+    #[test]
+    fn test_heading() {
+        let mut nmea = Nmea::new();
+        assert_eq!(nmea.heading_true(), None);
+        nmea.parse("$HCHDG,98.3,0.0,E,12.6,W*57").unwrap();
+        assert!((nmea.heading_magnetic().unwrap() - 98.3).abs() < 1e-4);
+        assert!((nmea.heading_true().unwrap() - 85.7).abs() < 1e-4);
+
+        nmea.parse("$GPHDT,274.07,T*03").unwrap();
+        assert_eq!(nmea.heading_true(), Some(274.07));
+        nmea.parse("$HCHDM,238.5,M*25").unwrap();
+        assert_eq!(nmea.heading_magnetic(), Some(238.5));
+        assert_eq!(nmea.heading_true(), Some(274.07));
+    }
+
+    #[test]
+    fn test_water_speed_and_current() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$IIVHW,245.1,T,245.1,M,000.01,N,000.01,K*55")
+            .unwrap();
+        assert_eq!(nmea.speed_through_water(), Some(0.01));
+        assert_eq!(nmea.heading_true(), Some(245.1));
+        assert_eq!(nmea.current(), None);
+
+        // 10 knots over ground due north, 8 knots through water
+        nmea.parse("$GPRMC,225446,A,4916.45,N,12311.12,W,010.0,000.0,191194,020.3,E*6A")
+            .unwrap();
+        nmea.parse("$GPHDT,000.0,T*35").unwrap();
+        nmea.parse("$IIVHW,,T,,M,8.0,N,,K*73").unwrap();
+        let (set, drift) = nmea.current().unwrap();
+        assert_relative_eq!(set, 0.);
+        assert_relative_eq!(drift, 2.);
+
+        // Leeway to port
+        nmea.parse("$IIVBW,10.0,-1.0,A,,,V*49").unwrap();
+        let (set, drift) = nmea.current().unwrap();
+        assert_relative_eq!(set, 90.);
+        assert_relative_eq!(drift, 1.);
+
+        // Invalid water speed is ignored
+        nmea.parse("$IIVBW,8.0,-1.0,V,,,V*67").unwrap();
+        assert_eq!(nmea.speed_through_water(), Some(10.));
+
+        nmea.parse("$IIVLW,7803.2,N,12.5,N,7900.1,N,13.0,N*48")
+            .unwrap();
+        assert_eq!(nmea.log().total_water_distance, Some(7803.2));
+        assert_eq!(nmea.log().trip_ground_distance, Some(13.));
+    }
+
+    #[test]
+    fn test_steering_and_engines() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$TIROT,12.3,A*0B").unwrap();
+        assert_eq!(nmea.rate_of_turn(), Some(12.3));
+        nmea.parse("$IIROT,,V*1F").unwrap();
+        assert_eq!(nmea.rate_of_turn(), Some(12.3));
+
+        nmea.parse("$IIRSA,-5.2,A,-4.8,A*4B").unwrap();
+        assert_eq!(nmea.rudder_angle(), Some(-5.2));
+        assert_eq!(nmea.port_rudder_angle(), Some(-4.8));
+        nmea.parse("$IIRSA,10.5,A,,V*4D").unwrap();
+        assert_eq!(nmea.rudder_angle(), Some(10.5));
+        assert_eq!(nmea.port_rudder_angle(), Some(-4.8));
+
+        nmea.parse("$IIRPM,E,1,2418.2,10.5,A*5F").unwrap();
+        nmea.parse("$IIRPM,S,2,-850.0,,A*4D").unwrap();
+        nmea.parse("$IIRPM,E,1,,,V*41").unwrap();
+        assert_eq!(nmea.rpm(RpmSource::Engine, 1).unwrap().speed, Some(2418.2));
+        assert_eq!(nmea.rpm(RpmSource::Shaft, 2).unwrap().speed, Some(-850.));
+        assert_eq!(nmea.rpm(RpmSource::Shaft, 1), None);
+    }
+
+    #[test]
+    fn test_datum() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+            .unwrap();
+        assert_eq!(nmea.datum(), None);
+        assert_eq!(nmea.wgs84_latitude(), nmea.latitude());
+        assert_eq!(nmea.wgs84_longitude(), nmea.longitude());
+
+        nmea.parse("$GPDTM,S85,,0.12,S,0.30,W,12.5,W84*53").unwrap();
+        assert_eq!(&nmea.datum().unwrap().local_datum.unwrap(), "S85");
+        assert_relative_eq!(
+            nmea.wgs84_latitude().unwrap(),
+            53. + 21.6802 / 60. + 0.002,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            nmea.wgs84_longitude().unwrap(),
+            -(6. + 30.3372 / 60.) + 0.005,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(nmea.wgs84_altitude().unwrap(), 61.7 - 12.5);
+
+        nmea.parse("$GPDTM,999,A,1.5,N,2.5,E,,P90*63").unwrap();
+        assert!(nmea.latitude().is_some());
+        assert_eq!(nmea.wgs84_latitude(), None);
+
+        nmea.parse("$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F").unwrap();
+        assert_eq!(nmea.wgs84_latitude(), nmea.latitude());
+        assert_eq!(nmea.wgs84_altitude(), nmea.altitude());
+    }
+
+    #[test]
+    fn test_grs_residuals() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$GPGSV,1,1,03,05,45,120,40,07,30,250,35,13,60,010,42*4F")
+            .unwrap();
+        nmea.parse("$GPGSA,A,3,05,07,13,,,,,,,,,,1.7,1.0,1.3*37")
+            .unwrap();
+        nmea.parse("$GPGRS,120000.00,0,1.5,-0.5,0.25,,,,,,,,,,1,1*79")
+            .unwrap();
+
+        let residuals: Vec<_> = nmea
+            .satellites()
+            .iter()
+            .map(|sat| (sat.prn(), sat.residual()))
+            .collect();
+        assert_eq!(
+            residuals,
+            [(5, Some(1.5)), (7, Some(-0.5)), (13, Some(0.25))]
+        );
+
+        // Residuals stay linked when the satellites are updated
+        nmea.parse("$GPGSV,1,1,03,05,45,120,41,07,30,250,35,13,60,010,42*4E")
+            .unwrap();
+        assert_eq!(nmea.satellites()[0].snr(), Some(41.));
+        assert_eq!(nmea.satellites()[0].residual(), Some(1.5));
+    }
+
+    #[test]
+    fn test_grs_residuals_per_system() {
+        let mut nmea = Nmea::new();
+        for s in &[
+            "$GPGSV,1,1,02,05,45,120,40,07,30,250,35*7D",
+            "$GLGSV,1,1,02,70,45,120,40,71,30,250,35*62",
+            "$GNGSA,A,3,05,07,,,,,,,,,,,1.7,1.0,1.3,1*36",
+            "$GNGSA,A,3,70,71,,,,,,,,,,,1.7,1.0,1.3,2*36",
+            "$GNGRS,120000.00,0,1.5,-0.5,,,,,,,,,,,1,1*7E",
+            "$GNGRS,120000.00,0,2.5,-2.5,,,,,,,,,,,2,1*7C",
+        ] {
+            nmea.parse(s).unwrap();
+        }
+
+        let mut residuals: Vec<_> = nmea
+            .satellites()
+            .iter()
+            .map(|sat| (sat.prn(), sat.residual()))
+            .collect();
+        residuals.sort_by_key(|(prn, _)| *prn);
+        assert_eq!(
+            residuals,
+            [
+                (5, Some(1.5)),
+                (7, Some(-0.5)),
+                (70, Some(2.5)),
+                (71, Some(-2.5))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_almanac_store() {
+        let mut nmea = Nmea::new();
+        nmea.parse(
+            "$GPALM,2,1,01,2210,00,0cf5,90,0a3b,fd3f,a10d2f,6b6c7e,ab3b6c,3c1a0e,7f3,ffe*70",
+        )
+        .unwrap();
+        assert!(!nmea.is_almanac_complete());
+        nmea.parse("$GPALM,2,2,02,,,,,,,,,,,,*79").unwrap();
+        assert!(nmea.is_almanac_complete());
+
+        let prns: Vec<_> = nmea.almanacs().map(|alm| alm.prn).collect();
+        assert_eq!(prns, [1]);
+        assert_eq!(nmea.almanac(1).unwrap().week, Some(2210));
+        assert_eq!(nmea.almanac(2), None);
+
+        // A new dump keeps the almanacs of the previous one
+        nmea.parse(
+            "$GPALM,1,1,15,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001*77",
+        )
+        .unwrap();
+        assert!(nmea.is_almanac_complete());
+        let prns: Vec<_> = nmea.almanacs().map(|alm| alm.prn).collect();
+        assert_eq!(prns, [1, 15]);
+
+        // An interrupted dump is not complete
+        nmea.parse(
+            "$GPALM,2,1,01,2210,00,0cf5,90,0a3b,fd3f,a10d2f,6b6c7e,ab3b6c,3c1a0e,7f3,ffe*70",
+        )
+        .unwrap();
+        nmea.parse(
+            "$GPALM,2,1,01,2210,00,0cf5,90,0a3b,fd3f,a10d2f,6b6c7e,ab3b6c,3c1a0e,7f3,ffe*70",
+        )
+        .unwrap();
+        assert!(!nmea.is_almanac_complete());
+    }
+
+    #[test]
+    fn test_water_temperature() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$IIMTW,17.9,C*1C").unwrap();
+        assert_eq!(nmea.water_temperature(), Some(17.9));
+        nmea.parse("$IIMTW,64.4,F*10").unwrap();
+        assert_relative_eq!(nmea.water_temperature().unwrap(), 18.);
+    }
+
+    #[test]
+    fn test_proprietary_decoder() {
+        fn decode_xyz(
+            nmea: &mut Nmea,
+            sentence: &ProprietarySentence,
+        ) -> Result<(), NmeaError<'static>> {
+            let mut fields = sentence.fields();
+            match (fields.next(), fields.next().map(str::parse)) {
+                (Some("TEMP"), Some(Ok(temperature))) => {
+                    nmea.water_temperature = Some(temperature);
+                    Ok(())
+                }
+                _ => Err(NmeaError::Unsupported(SentenceType::Proprietary)),
+            }
+        }
+
+        let mut nmea = Nmea::new();
+        assert_eq!(
+            nmea.parse("$PXYZ,TEMP,21.5*1F"),
+            Err(NmeaError::Unsupported(SentenceType::Proprietary))
+        );
+        assert_eq!(
+            nmea.parse_for_fix(b"$PXYZ,TEMP,21.5*1F"),
+            Ok(FixType::Invalid)
+        );
+
+        nmea.register_proprietary_decoder("XYZ", decode_xyz)
+            .unwrap();
+        assert_eq!(
+            nmea.parse("$PXYZ,TEMP,21.5*1F"),
+            Ok(SentenceType::Proprietary)
+        );
+        assert_eq!(nmea.water_temperature(), Some(21.5));
+        assert!(nmea.parse("$PXYZ,TEMP,*07").is_err());
+        assert_eq!(
+            nmea.parse("$PABC,1*0D"),
+            Err(NmeaError::Unsupported(SentenceType::Proprietary))
+        );
+
+        for manufacturer in &["WXYZ", "XY", "XŸ"] {
+            assert_eq!(
+                nmea.register_proprietary_decoder(manufacturer, decode_xyz),
+                Err(NmeaError::InvalidManufacturer)
+            );
+        }
+    }
+
+    #[test]
+    fn test_heading_monitor() {
+        let mut nmea = Nmea::new();
+        assert_eq!(nmea.heading_monitor(), &HeadingMonitorState::default());
+
+        nmea.parse("$HCHMS,HDG1,HDG2,5.0*59").unwrap();
+        assert_eq!(nmea.heading_monitor().limit, Some(5.));
+        assert!(!nmea.heading_monitor().alarm);
+
+        nmea.parse("$HCHMR,HDG1,HDG2,5.0,7.8,V,123.4,A,M,,,131.2,A,T,,,,*33")
+            .unwrap();
+        assert_eq!(nmea.heading_monitor().difference, Some(7.8));
+        assert!(nmea.heading_monitor().alarm);
+
+        nmea.parse("$HCHMR,HDG1,HDG2,5.0,2.1,A,123.4,A,M,1.5,E,125.5,A,T,,,3.2,W*3D")
+            .unwrap();
+        assert!(!nmea.heading_monitor().alarm);
+
+        // Without a warning flag the difference is checked against the limit
+        nmea.parse("$HCHMR,HDG1,HDG2,,6.0,,120.0,A,T,,,126.0,A,T,,,,*5D")
+            .unwrap();
+        assert_eq!(nmea.heading_monitor().limit, Some(5.));
+        assert!(nmea.heading_monitor().alarm);
+    }
+
+    #[test]
+    fn test_depth() {
+        let mut nmea = Nmea::new();
+        assert_eq!(nmea.depth_below_transducer(), None);
+
+        nmea.parse("$SDDBT,7.8,f,2.4,M,1.3,F*0D").unwrap();
+        nmea.parse("$SDDBK,22.1,f,6.7,M,3.7,F*2D").unwrap();
+        nmea.parse("$SDDBS,,f,,M,5.5,F*01").unwrap();
+        assert_eq!(nmea.depth_below_transducer(), Some(2.4));
+        assert_eq!(nmea.depth_below_keel(), Some(6.7));
+        assert_relative_eq!(nmea.depth_below_surface().unwrap(), 10.0584);
+
+        nmea.parse("$SDDPT,2.4,0.5,100*49").unwrap();
+        assert_relative_eq!(nmea.depth_below_surface().unwrap(), 2.9);
+        assert_eq!(nmea.depth_below_keel(), Some(6.7));
+
+        nmea.parse("$SDDPT,2.4,-1.2*7F").unwrap();
+        assert_relative_eq!(nmea.depth_below_keel().unwrap(), 1.2);
+        assert_relative_eq!(nmea.depth_below_surface().unwrap(), 10.0584);
+    }
+
+    #[test]
+    fn test_wind() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$IIMWV,090.0,R,10.0,N,A*05").unwrap();
+        assert_eq!(nmea.wind().apparent_angle, Some(90.));
+        assert_eq!(nmea.wind().apparent_speed, Some(10.));
+        assert_eq!(nmea.wind().true_speed, None);
+
+        // 10 knots due north, the wind from the beam is from the quarter
+        nmea.parse("$GPRMC,225446,A,4916.45,N,12311.12,W,010.0,000.0,191194,020.3,E*6A")
+            .unwrap();
+        nmea.parse("$IIMWV,090.0,R,10.0,N,A*05").unwrap();
+        let wind = nmea.wind();
+        assert_relative_eq!(wind.true_speed.unwrap(), 14.142136);
+        assert_relative_eq!(wind.true_direction.unwrap(), 135., epsilon = 1e-4);
+        assert_relative_eq!(wind.true_angle.unwrap(), 135., epsilon = 1e-4);
+
+        // Heading east while moving north
+        nmea.parse("$GPHDT,090.0,T*3C").unwrap();
+        nmea.parse("$IIVWR,045.0,L,12.6,N,6.5,M,23.3,K*52").unwrap();
+        let wind = nmea.wind();
+        assert_relative_eq!(wind.apparent_angle.unwrap(), 315.);
+        assert_relative_eq!(wind.true_speed.unwrap(), 8.976029, epsilon = 1e-4);
+        assert_relative_eq!(wind.true_direction.unwrap(), 96.978, epsilon = 1e-3);
+        assert_relative_eq!(wind.true_angle.unwrap(), 6.978, epsilon = 1e-3);
+
+        // Invalid data is ignored
+        nmea.parse("$WIMWV,,R,,N,V*34").unwrap();
+        assert_relative_eq!(nmea.wind().apparent_angle.unwrap(), 315.);
+
+        nmea.parse("$WIMWD,270.0,T,275.5,M,12.0,N,6.2,M*6D")
+            .unwrap();
+        let wind = nmea.wind();
+        assert_eq!(wind.true_direction, Some(270.));
+        assert_eq!(wind.true_speed, Some(12.));
+        assert_eq!(wind.true_angle, Some(180.));
+
+        nmea.parse("$IIMWV,041.0,T,10.5,N,A*0A").unwrap();
+        assert_eq!(nmea.wind().true_direction, Some(131.));
+
+        nmea.parse("$IIVPW,4.5,N,,M*7D").unwrap();
+        assert_eq!(nmea.wind().speed_parallel_to_wind, Some(4.5));
+    }
+
+    #[test]
+    fn test_navigation_void_sentences() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$GPAPB,V,V,0.10,R,N,V,V,011,M,004,011,M,011,M*0E")
+            .unwrap();
+        nmea.parse("$GPRMB,V,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*37")
+            .unwrap();
+        assert_eq!(nmea.navigation(), &NavigationState::default());
+    }
+
+    #[test]
+    fn test_navigation_state() {
+        let mut nmea = Nmea::new();
+        assert_eq!(nmea.navigation(), &NavigationState::default());
+
+        nmea.parse("$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20")
+            .unwrap();
+        nmea.parse("$GPAPB,A,A,0.10,R,N,V,V,011,M,004,011,M,011,M*0E")
+            .unwrap();
+        let nav = nmea.navigation();
+        assert_eq!(&nav.origin_waypoint_id.unwrap(), "003");
+        assert_eq!(&nav.destination_waypoint_id.unwrap(), "004");
+        assert_eq!(nav.cross_track_error, Some(0.1));
+        assert_eq!(nav.steer_direction, Some(SteerDirection::Right));
+        assert_eq!(nav.bearing_to_destination_true, Some(52.5));
+        assert_eq!(nav.bearing_to_destination_magnetic, Some(11.));
+        assert_eq!(nav.heading_to_steer_magnetic, Some(11.));
+        assert_eq!(nav.distance_to_destination, Some(1.3));
+        assert_eq!(nav.arrived, Some(false));
+
+        // New destination, state of the previous one is dropped
+        nmea.parse("$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21")
+            .unwrap();
+        let nav = nmea.navigation();
+        assert_eq!(&nav.destination_waypoint_id.unwrap(), "EGLM");
+        assert_eq!(nav.origin_waypoint_id, None);
+        assert_eq!(nav.cross_track_error, None);
+        assert_eq!(nav.bearing_to_destination_true, Some(213.8));
+        assert_eq!(nav.distance_to_destination, Some(4.6));
+
+        nmea.parse("$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45")
+            .unwrap();
+        nmea.parse("$GPXTE,A,A,0.67,L,N,A*02").unwrap();
+        nmea.parse("$GPXTE,V,V,,,N,S*43").unwrap();
+        nmea.parse("$GPAAM,V,V,0.5,K,POINTB*18").unwrap();
+        let nav = nmea.navigation();
+        assert_eq!(&nav.destination_waypoint_id.unwrap(), "POINTB");
+        assert_eq!(&nav.origin_waypoint_id.unwrap(), "POINTA");
+        assert_eq!(nav.cross_track_error, Some(0.67));
+        assert_eq!(nav.steer_direction, Some(SteerDirection::Left));
+        assert_eq!(nav.arrived, Some(false));
+
+        nmea.parse("$GPBWR,081837,3751.65,S,14507.36,E,015.0,T,002.4,M,0005.8,N,EGLM*37")
+            .unwrap();
+        nmea.parse("$GPWCV,2.6,N,EGLM,A*73").unwrap();
+        assert_eq!(
+            nmea.parse("$GPBWW,213.8,T,218.0,M,TOWPT,FROMWPT*42"),
+            Ok(SentenceType::BWW)
+        );
+        let nav = nmea.navigation();
+        assert_eq!(&nav.destination_waypoint_id.unwrap(), "EGLM");
+        assert_eq!(nav.origin_waypoint_id, None);
+        assert_eq!(nav.distance_to_destination, Some(5.8));
+        assert_eq!(nav.closing_velocity, Some(2.6));
+
+        assert_eq!(nmea.time_to_go(), None);
+        nmea.parse("$GPZTG,145832.12,042359.17,EGLM*74").unwrap();
+        assert_eq!(
+            nmea.time_to_go(),
+            Some(Duration::hours(4) + Duration::minutes(23) + Duration::milliseconds(59_170))
+        );
+        assert_eq!(nmea.navigation().closing_velocity, Some(2.6));
+
+        // Time to go to another waypoint starts a new leg
+        nmea.parse("$GPZTG,145832.12,042359.17,WPT*24").unwrap();
+        assert_eq!(nmea.navigation().closing_velocity, None);
+        assert!(nmea.time_to_go().is_some());
+    }
+
+    #[test]
+    fn test_ais_reassembly() {
+        let mut nmea = Nmea::new();
+        assert_eq!(
+            nmea.parse(
+                "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C"
+            ),
+            Ok(SentenceType::VDM)
+        );
+        assert_eq!(nmea.take_ais_message(), None);
+
+        // Single sentence message between the fragments
+        nmea.parse("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C")
+            .unwrap();
+        match nmea.take_ais_message() {
+            Some(AisMessage::PositionReport(report)) => assert_eq!(report.mmsi, 477553000),
+            message => panic!("Unexpected message {:?}", message),
+        }
+        assert_eq!(nmea.take_ais_message(), None);
+
+        nmea.parse("!AIVDM,2,2,1,A,88888888880,2*25").unwrap();
+        match nmea.take_ais_message() {
+            Some(AisMessage::StaticAndVoyageData(data)) => {
+                assert_eq!(data.mmsi, 351759000);
+                assert_eq!(&data.vessel_name, "EVER DIADEM");
+                assert_eq!(&data.destination, "NEW YORK");
+            }
+            message => panic!("Unexpected message {:?}", message),
+        }
+
+        // The first fragment is missing
+        nmea.parse("!AIVDM,2,2,1,A,88888888880,2*25").unwrap();
+        assert_eq!(nmea.take_ais_message(), None);
+
+        nmea.parse("!AIVDO,1,1,,,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*0F")
+            .unwrap();
+        assert!(matches!(
+            nmea.take_ais_message(),
+            Some(AisMessage::ClassBPositionReport(_))
+        ));
+    }
+
+    #[test]
+    fn test_target_table() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$RATTM,01,1.34,320.2,T,12.5,145.0,T,0.22,4.5,N,SHIP1,T,,100523.00,A*02")
+            .unwrap();
+        nmea.parse("$RATTM,02,1.852,090.0,R,18.52,270.0,R,,,K,,Q,,100520.00,M*03")
+            .unwrap();
+        nmea.parse("$RATLL,01,4917.24,N,12309.57,W,SHIP1,100524.00,T,*0E")
+            .unwrap();
+        nmea.parse("$RATLL,03,4917.00,N,12310.00,W,,100530.00,T,*36")
+            .unwrap();
+        let numbers: Vec<u8> = nmea.targets().map(|target| target.number).collect();
+        assert_eq!(numbers, [1, 2, 3]);
+
+        let target = nmea.target(1).unwrap();
+        assert_eq!(&target.name.unwrap(), "SHIP1");
+        assert_eq!(target.distance, Some(1.34));
+        assert_eq!(target.status, Some(TargetStatus::Tracking));
+        assert_relative_eq!(target.latitude.unwrap(), 49. + 17.24 / 60.);
+        assert_eq!(target.last_update, NaiveTime::from_hms_opt(10, 5, 24));
+
+        // Target 2 was not updated for more than a minute
+        nmea.parse("$RATTM,01,1.30,320.0,T,12.5,145.0,T,0.20,4.4,N,SHIP1,T,,100623.00,A*04")
+            .unwrap();
+        let numbers: Vec<u8> = nmea.targets().map(|target| target.number).collect();
+        assert_eq!(numbers, [1, 3]);
+        assert_eq!(nmea.target(1).unwrap().distance, Some(1.3));
+
+        nmea.parse("$RATTM,03,,,,,,,,,N,,L,*73").unwrap();
+        let numbers: Vec<u8> = nmea.targets().map(|target| target.number).collect();
+        assert_eq!(numbers, [1]);
+    }
+
+    #[test]
+    fn test_route_assembly() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$GPWPL,4917.16,N,12310.64,W,003*65").unwrap();
+        nmea.parse("$GPWPL,4917.24,N,12309.57,W,004*6B").unwrap();
+        assert_eq!(nmea.route(), None);
+
+        nmea.parse("$GPRTE,2,2,c,0,PCRESY,GRYRIE,GCORIO,GWERR,GWESTG,7FED*34")
+            .unwrap();
+        assert_eq!(nmea.route(), None);
+        nmea.parse(
+            "$GPRTE,2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND,PYAMBU,PPFAIR,PWARRN,PMORTL,PLISMR*73",
+        )
+        .unwrap();
+        let route = nmea.route().unwrap();
+        assert_eq!(route.mode, RteMode::Complete);
+        assert_eq!(route.waypoints.len(), 15);
+        assert_eq!(&route.waypoints[0].id, "PBRCPK");
+        assert_eq!(&route.waypoints[14].id, "7FED");
+        assert_eq!(route.waypoints[0].latitude, None);
+
+        nmea.parse("$GPRTE,1,1,w,MYROUTE,003,004*69").unwrap();
+        let route = nmea.route().unwrap();
+        assert_eq!(route.mode, RteMode::Working);
+        assert_eq!(&route.route_id.unwrap(), "MYROUTE");
+        assert_eq!(
+            route.waypoints[1],
+            RouteWaypoint {
+                id: ArrayString::from("004").unwrap(),
+                latitude: Some(49. + 17.24 / 60.),
+                longitude: Some(-(123. + 9.57 / 60.)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_for_fix_gns() {
+        let mut nmea =
+            Nmea::create_for_navigation(&[SentenceType::RMC, SentenceType::GNS]).unwrap();
+        let log = [
+            (
+                "$GNRMC,014035.00,A,4332.69262,S,17235.48549,E,0.014,,220118,,,R*67",
+                FixType::Invalid,
+            ),
+            (
+                "$GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,*70",
+                FixType::Rtk,
+            ),
+            (
+                "$GNRMC,014036.00,A,4332.69262,S,17235.48549,E,0.014,,220118,,,R*64",
+                FixType::Invalid,
+            ),
+            (
+                "$GNGNS,014036.00,4332.69262,S,17235.48549,E,NN,13,0.9,25.63,11.24,,*73",
+                FixType::Invalid,
+            ),
+        ];
+        for (i, item) in log.iter().enumerate() {
+            let res = nmea.parse_for_fix(item.0.as_bytes()).unwrap();
+            println!("parse result({}): {:?}, {:?}", i, res, nmea.fix_time);
+            assert_eq!(res, item.1);
+            if i == 1 {
+                assert_eq!(nmea.fix_satellites(), Some(13));
+                assert_eq!(nmea.altitude(), Some(25.63));
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_for_fix_pubx() {
+        let mut nmea = Nmea::create_for_navigation(&[SentenceType::PUBX]).unwrap();
+        let log = [
+            (
+                "$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F",
+                FixType::Gps,
+            ),
+            (
+                "$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D",
+                FixType::Invalid,
+            ),
+            (
+                "$PUBX,00,000114.00,0000.00000,N,00000.00000,E,0.000,NF,5303302,3750001,0.000,0.00,0.000,,99.99,99.99,99.99,0,0,0*2C",
+                FixType::Invalid,
+            ),
+            ("$PUBX,41,1,0007,0003,19200,0*25", FixType::Invalid),
+        ];
+        for (i, item) in log.iter().enumerate() {
+            let res = nmea.parse_for_fix(item.0.as_bytes()).unwrap();
+            assert_eq!(res, item.1);
+            if i == 0 {
+                assert_eq!(nmea.fix_time, NaiveTime::from_hms_opt(8, 13, 50));
+                assert_relative_eq!(nmea.latitude().unwrap(), 47. + 17.11321 / 60.);
+                assert_relative_eq!(nmea.longitude().unwrap(), 8. + 33.915187 / 60.);
+                assert_relative_eq!(nmea.true_course.unwrap(), 77.52);
+                assert_eq!(nmea.fix_satellites(), Some(9));
+                assert_eq!(nmea.altitude(), None);
+                assert_eq!(nmea.horizontal_accuracy(), Some(2.1));
+                assert_eq!(nmea.vertical_accuracy(), Some(2.0));
+            }
+        }
+
+        let mut nmea = Nmea::new();
+        assert_eq!(
+            nmea.parse("$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F"),
+            Ok(SentenceType::PUBX)
+        );
+        assert_eq!(nmea.fix_type(), Some(FixType::Gps));
+        assert_eq!(nmea.hdop(), Some(0.92));
+
+        assert_eq!(
+            nmea.parse("$PUBX,00,000114.00,0000.00000,N,00000.00000,E,0.000,NF,5303302,3750001,0.000,0.00,0.000,,99.99,99.99,99.99,0,0,0*2C"),
+            Ok(SentenceType::PUBX)
+        );
+        assert_eq!(nmea.fix_type(), Some(FixType::Invalid));
+        assert_eq!(nmea.latitude(), None);
+        assert_eq!(nmea.longitude(), None);
+
+        // Messages we don't parse go to the decoder registered for u-blox
+        assert_eq!(
+            nmea.parse("$PUBX,41,1,0007,0003,19200,0*25"),
+            Err(NmeaError::Unsupported(SentenceType::Proprietary))
+        );
+        nmea.register_proprietary_decoder("UBX", |_, sentence| {
+            assert_eq!(sentence.fields().next(), Some("41"));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(
+            nmea.parse("$PUBX,41,1,0007,0003,19200,0*25"),
+            Ok(SentenceType::Proprietary)
+        );
+    }
+
+    #[test]
+    fn test_pmtk_response() {
+        let mut nmea = Nmea::new();
+        assert_eq!(nmea.take_pmtk_response(), None);
+        assert_eq!(nmea.parse("$PMTK001,220,3*30"), Ok(SentenceType::PMTK));
+        assert_eq!(
+            nmea.take_pmtk_response(),
+            Some(PmtkData::Ack(PmtkAckData {
+                command: 220,
+                flag: PmtkAckFlag::Succeeded,
+            }))
+        );
+        assert_eq!(nmea.take_pmtk_response(), None);
+
+        assert_eq!(
+            nmea.parse_for_fix(b"$PMTK500,1000,0,0,0,0*1A"),
+            Ok(FixType::Invalid)
+        );
+        assert_eq!(nmea.take_pmtk_response(), Some(PmtkData::UpdateRate(1000)));
+
+        // Startup messages, the packet types we don't parse are ignored
+        for s in &["$PMTK011,MTKGPS*08", "$PMTK010,001*2E"] {
+            assert_eq!(nmea.parse_for_fix(s.as_bytes()), Ok(FixType::Invalid));
+            assert_eq!(
+                nmea.parse(s),
+                Err(NmeaError::Unsupported(SentenceType::Proprietary))
+            );
+        }
+        assert_eq!(nmea.take_pmtk_response(), None);
+    }
+
+    #[test]
+    fn test_garmin_sentences() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+            .unwrap();
+        assert_eq!(nmea.parse("$PGRMZ,246,f,3*1B"), Ok(SentenceType::PGRMZ));
+        assert_eq!(nmea.altitude(), Some(61.7));
+        assert_relative_eq!(nmea.baro_altitude().unwrap(), 74.9808);
+
+        assert_eq!(
+            nmea.parse("$PGRME,15.0,M,45.0,M,25.0,M*1C"),
+            Ok(SentenceType::PGRME)
+        );
+        assert_eq!(nmea.horizontal_accuracy(), Some(15.));
+        assert_eq!(nmea.vertical_accuracy(), Some(45.));
+        assert_eq!(nmea.spherical_accuracy(), Some(25.));
+
+        assert_eq!(nmea.map_datum(), None);
+        assert_eq!(nmea.parse("$PGRMM,WGS 84*06"), Ok(SentenceType::PGRMM));
+        assert_eq!(nmea.map_datum(), Some("WGS 84"));
+
+        // Other Garmin sentences are left to registered decoders
+        assert_eq!(
+            nmea.parse("$PGRMT,GPS 15L/15H VER 2.05,,,,,,,,*67"),
+            Err(NmeaError::Unsupported(SentenceType::Proprietary))
+        );
+    }
+
+    #[test]
+    fn test_psti() {
+        let mut nmea = Nmea::create_for_navigation(&[SentenceType::PSTI]).unwrap();
+        assert_eq!(
+            nmea.parse_for_fix(b"$PSTI,030,044606.000,A,2447.0924110,N,12100.5227860,E,103.323,0.00,0.00,0.00,180915,R,1.2,4.2*02"),
+            Ok(FixType::Rtk)
+        );
+        assert_eq!(nmea.fix_date, NaiveDate::from_ymd_opt(2015, 9, 18));
+        assert_relative_eq!(nmea.latitude().unwrap(), 24. + 47.092411 / 60.);
+        assert_eq!(nmea.altitude(), Some(103.323));
+        assert_eq!(nmea.rtk_age(), Some(1.2));
+        assert_eq!(nmea.rtk_ratio(), Some(4.2));
+
+        assert_eq!(
+            nmea.parse_for_fix(b"$PSTI,036,054314.000,030521,191.69,-16.35,0.00,R*4D"),
+            Ok(FixType::Invalid)
+        );
+        assert_eq!(nmea.heading_true(), Some(191.69));
+        assert_eq!(nmea.attitude().unwrap().pitch, Some(-16.35));
+
+        // Invalid attitude and baseline are ignored
+        nmea.parse("$PSTI,036,054315.000,030521,,,,N*54").unwrap();
+        assert_eq!(nmea.heading_true(), Some(191.69));
+        assert_eq!(nmea.baseline(), None);
+        nmea.parse("$PSTI,032,041457.000,170316,A,R,0.603,-0.837,-0.089,1.036,144.22,,,,,*1C")
+            .unwrap();
+        assert_eq!(nmea.baseline().unwrap().length, Some(1.036));
+
+        assert_eq!(
+            nmea.parse_for_fix(b"$PSTI,030,044607.000,V,,,,,,,,,180915,N,,*02"),
+            Ok(FixType::Invalid)
+        );
+        assert_eq!(nmea.rtk_age(), None);
+        assert_eq!(nmea.attitude().unwrap().heading, Some(191.69));
+
+        // Message IDs we don't parse are ignored
+        assert_eq!(nmea.parse_for_fix(b"$PSTI,001,1*1E"), Ok(FixType::Invalid));
+
+        // The century comes from ZDA like for RMC
+        nmea.parse("$GNZDA,181604.00,12,09,2090,00,00*73").unwrap();
+        nmea.parse("$PSTI,030,044606.000,A,2447.0924110,N,12100.5227860,E,103.323,0.00,0.00,0.00,180915,R,1.2,4.2*02")
+            .unwrap();
+        assert_eq!(nmea.fix_date, NaiveDate::from_ymd_opt(2115, 9, 18));
+    }
+
     #[test]
     fn test_some_reciever() {
         let lines = [
@@ -1156,4 +3573,21 @@ mod tests {
         assert_eq!(54, nmea.fix_timestamp().unwrap().minute());
         assert_eq!(44, nmea.fix_timestamp().unwrap().second());
     }
+
+    #[test]
+    fn test_zda_date_precedence() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$GNZDA,181604.00,12,09,2090,00,00*73").unwrap();
+        assert_eq!(nmea.fix_date, NaiveDate::from_ymd_opt(2090, 9, 12));
+
+        // Without ZDA the 2 digit year 90 would be taken as 1990
+        nmea.parse("$GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191190,020.3,E,A*2F")
+            .unwrap();
+        assert_eq!(nmea.fix_date, NaiveDate::from_ymd_opt(2090, 11, 19));
+
+        // Century rollover
+        nmea.parse("$GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,010101,020.3,E,A*2F")
+            .unwrap();
+        assert_eq!(nmea.fix_date, NaiveDate::from_ymd_opt(2101, 1, 1));
+    }
 }
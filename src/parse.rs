@@ -2,9 +2,10 @@ use core::str;
 use core::fmt;
 
 use nom::{
-    bytes::complete::{take, take_until},
-    character::complete::char,
-    combinator::map_res,
+    branch::alt,
+    bytes::complete::{tag, take, take_until, take_while},
+    character::complete::{char, one_of},
+    combinator::{map_res, opt},
     sequence::preceded,
     IResult,
 };
@@ -13,10 +14,22 @@ pub use crate::sentences::*;
 use crate::SentenceType;
 
 pub const SENTENCE_MAX_LEN: usize = 102;
+/// Limit for proprietary sentences, u-blox PUBX,03 lists all the tracked
+/// satellites in a single sentence
+pub const PROPRIETARY_SENTENCE_MAX_LEN: usize = 512;
 
+/// A sentence split into its address and data fields.
+///
+/// Proprietary sentences, which start with `$P`, have a `P` talker ID and
+/// keep the three letter manufacturer mnemonic, the message ID is then
+/// whatever follows the mnemonic up to the first comma and may be empty.
+/// They may also have no comma and no data fields at all, e.g. `$PMTK101*32`.
 pub struct NmeaSentence<'a> {
     pub talker_id: &'a [u8],
+    pub manufacturer: Option<&'a [u8]>,
     pub message_id: &'a [u8],
+    /// False when there is no comma after the message ID
+    pub has_data: bool,
     pub data: &'a [u8],
     pub checksum: u8,
 }
@@ -26,11 +39,16 @@ impl<'a> NmeaSentence<'a> {
         checksum(
             self.talker_id
                 .iter()
+                .chain(self.manufacturer.unwrap_or_default().iter())
                 .chain(self.message_id.iter())
-                .chain(&[b','])
+                .chain(if self.has_data { &b","[..] } else { &[] })
                 .chain(self.data.iter()),
         )
     }
+
+    pub fn is_proprietary(&self) -> bool {
+        self.manufacturer.is_some()
+    }
 }
 
 pub fn checksum<'a, I: Iterator<Item = &'a u8>>(bytes: I) -> u8 {
@@ -46,8 +64,8 @@ fn parse_checksum(i: &[u8]) -> IResult<&[u8], u8> {
     map_res(preceded(char('*'), take(2usize)), parse_hex)(i)
 }
 
-fn do_parse_nmea_sentence(i: &[u8]) -> IResult<&[u8], NmeaSentence> {
-    let (i, talker_id) = preceded(char('$'), take(2usize))(i)?;
+fn do_parse_standard_sentence(i: &[u8]) -> IResult<&[u8], NmeaSentence<'_>> {
+    let (i, talker_id) = preceded(one_of("$!"), take(2usize))(i)?;
     let (i, message_id) = take(3usize)(i)?;
     let (i, _) = char(',')(i)?;
     let (i, data) = take_until("*")(i)?;
@@ -57,16 +75,42 @@ fn do_parse_nmea_sentence(i: &[u8]) -> IResult<&[u8], NmeaSentence> {
         i,
         NmeaSentence {
             talker_id,
+            manufacturer: None,
             message_id,
+            has_data: true,
             data,
             checksum,
         },
     ))
 }
 
+fn do_parse_proprietary_sentence(i: &[u8]) -> IResult<&[u8], NmeaSentence<'_>> {
+    let (i, talker_id) = preceded(char('$'), tag("P"))(i)?;
+    let (i, manufacturer) = take(3usize)(i)?;
+    let (i, message_id) = take_while(|c| c != b',' && c != b'*')(i)?;
+    let (i, data) = opt(preceded(char(','), take_until("*")))(i)?;
+    let (i, checksum) = parse_checksum(i)?;
+
+    Ok((
+        i,
+        NmeaSentence {
+            talker_id,
+            manufacturer: Some(manufacturer),
+            message_id,
+            has_data: data.is_some(),
+            data: data.unwrap_or_default(),
+            checksum,
+        },
+    ))
+}
+
+fn do_parse_nmea_sentence(i: &[u8]) -> IResult<&[u8], NmeaSentence<'_>> {
+    alt((do_parse_proprietary_sentence, do_parse_standard_sentence))(i)
+}
+
 pub fn parse_nmea_sentence<'a>(
     sentence: &'a [u8],
-) -> core::result::Result<NmeaSentence, NmeaError<'a>> {
+) -> core::result::Result<NmeaSentence<'a>, NmeaError<'a>> {
     /*
      * From gpsd:
      * We've had reports that on the Garmin GPS-10 the device sometimes
@@ -83,7 +127,12 @@ pub fn parse_nmea_sentence<'a>(
      * The current hog champion is the Skytraq S2525F8 which emits
      * a 100-character PSTI message.
      */
-    if sentence.len() > SENTENCE_MAX_LEN {
+    let max_len = if sentence.starts_with(b"$P") {
+        PROPRIETARY_SENTENCE_MAX_LEN
+    } else {
+        SENTENCE_MAX_LEN
+    };
+    if sentence.len() > max_len {
         Err(NmeaError::SentenceLength(sentence.len()))
     } else {
         Ok(do_parse_nmea_sentence(sentence)?.1)
@@ -92,14 +141,68 @@ pub fn parse_nmea_sentence<'a>(
 
 #[derive(Debug, PartialEq)]
 pub enum ParseResult {
+    AAM(AamData),
+    ALM(AlmData),
+    APB(ApbData),
+    BOD(BodData),
     BWC(BwcData),
+    BWR(BwcData),
+    BWW(BwwData),
+    DBK(DbkData),
+    DBS(DbsData),
+    DBT(DbtData),
+    DPT(DptData),
+    DTM(DtmData),
+    GBS(GbsData),
     GGA(GgaData),
+    RMB(RmbData),
     RMC(RmcData),
+    ROT(RotData),
+    RPM(RpmData),
+    RSA(RsaData),
+    RTE(RteData),
     GSV(GsvData),
     GSA(GsaData),
+    GST(GstData),
+    HDG(HdgData),
+    HDM(HdmData),
+    HDT(HdtData),
+    HMR(HmrData),
+    HMS(HmsData),
+    MTW(MtwData),
+    MWD(MwdData),
+    MWV(MwvData),
+    VBW(VbwData),
+    VDM(VdmData),
+    VDO(VdmData),
+    VHW(VhwData),
+    VLW(VlwData),
+    VPW(VpwData),
     VTG(VtgData),
+    VWR(VwrData),
+    WCV(WcvData),
+    WNC(WncData),
+    WPL(WplData),
     GLL(GllData),
+    GNS(GnsData),
+    GRS(GrsData),
+    TLL(TllData),
+    TTM(TtmData),
     TXT(TxtData),
+    XDR(XdrData),
+    XTE(XteData),
+    ZDA(ZdaData),
+    ZDL(ZdlData),
+    ZFO(ZfoData),
+    ZTG(ZtgData),
+    PGRME(PgrmeData),
+    PGRMM(PgrmmData),
+    PGRMZ(PgrmzData),
+    PMTK(PmtkData),
+    PSTI(PstiData),
+    PUBX(PubxData),
+    /// A proprietary sentence of a manufacturer without a built-in parser
+    Proprietary(ProprietarySentence),
     Unsupported(SentenceType),
 }
 
@@ -114,14 +217,19 @@ pub enum NmeaError<'a> {
     WrongSentenceHeader { expected: &'a [u8], found: &'a [u8] },
     /// The sentence could not be parsed because its format was invalid
     ParsingError(nom::Err<(&'a [u8], nom::error::ErrorKind)>),
-    /// The sentence was too long to be parsed, our current limit is `SENTENCE_MAX_LEN` characters
+    /// The sentence was too long to be parsed, our current limit is `SENTENCE_MAX_LEN` characters,
+    /// `PROPRIETARY_SENTENCE_MAX_LEN` for proprietary sentences
     SentenceLength(usize),
     /// The type of a GSV sentence was not a valid Gnss type
     InvalidGnssType,
+    /// The payload of an AIS message could not be decoded
+    InvalidAisPayload,
     /// The sentence has and maybe will never be implemented
     Unsupported(SentenceType),
     /// The provided navigation configuration was empty and thus invalid
     EmptyNavConfig,
+    /// The manufacturer mnemonic of a proprietary decoder was not three ASCII characters
+    InvalidManufacturer,
 }
 
 impl<'a> From<nom::Err<(&'a [u8], nom::error::ErrorKind)>> for NmeaError<'a> {
@@ -139,8 +247,10 @@ impl<'a> fmt::Display for NmeaError<'a> {
             NmeaError::ParsingError(e) => write!(f, "{}", e),
             NmeaError::SentenceLength(size) => write!(f, "The sentence was too long to be parsed, current limit is {} characters", size),
             NmeaError::InvalidGnssType => write!(f, "The type of a GSV sentence was not a valid Gnss type"),
+            NmeaError::InvalidAisPayload => write!(f, "The payload of an AIS message could not be decoded"),
             NmeaError::Unsupported(sentence) => write!(f, "Unsupported NMEA sentence {:?}", sentence),
             NmeaError::EmptyNavConfig => write!(f, "The provided navigation configuration was empty and thus invalid"),
+            NmeaError::InvalidManufacturer => write!(f, "The manufacturer mnemonic was not three ASCII characters"),
         }
     }
 }
@@ -150,16 +260,57 @@ impl<'a> std::error::Error for NmeaError<'a> {}
 
 
 /// parse nmea 0183 sentence and extract data from it
-pub fn parse(xs: &[u8]) -> Result<ParseResult, NmeaError> {
+pub fn parse(xs: &[u8]) -> Result<ParseResult, NmeaError<'_>> {
     let nmea_sentence = parse_nmea_sentence(xs)?;
     let calculated_checksum = nmea_sentence.calc_checksum();
 
     if nmea_sentence.checksum == calculated_checksum {
+        // PUBX and PSTI give their message type in the first data field, the
+        // messages we don't parse go to the decoders of the manufacturer
+        let first_field = nmea_sentence
+            .data
+            .split(|c| *c == b',')
+            .next()
+            .unwrap_or_default();
+        match (
+            nmea_sentence.manufacturer,
+            nmea_sentence.message_id,
+            first_field,
+        ) {
+            (Some(b"GRM"), b"E", _) => return Ok(ParseResult::PGRME(parse_pgrme(nmea_sentence)?)),
+            (Some(b"GRM"), b"M", _) => return Ok(ParseResult::PGRMM(parse_pgrmm(nmea_sentence)?)),
+            (Some(b"GRM"), b"Z", _) => return Ok(ParseResult::PGRMZ(parse_pgrmz(nmea_sentence)?)),
+            (Some(b"MTK"), b"001" | b"500" | b"514" | b"705", _) => {
+                return Ok(ParseResult::PMTK(parse_pmtk(nmea_sentence)?))
+            }
+            (Some(b"STI"), _, b"030" | b"032" | b"036") => {
+                return Ok(ParseResult::PSTI(parse_psti(nmea_sentence)?))
+            }
+            (Some(b"UBX"), _, b"00" | b"03" | b"04") => {
+                return Ok(ParseResult::PUBX(parse_pubx(nmea_sentence)?))
+            }
+            (Some(_), _, _) => {
+                return Ok(ParseResult::Proprietary(parse_proprietary(nmea_sentence)?))
+            }
+            (None, _, _) => {}
+        }
         match SentenceType::from_slice(nmea_sentence.message_id) {
+            SentenceType::AAM => Ok(ParseResult::AAM(parse_aam(nmea_sentence)?)),
+            SentenceType::ALM => Ok(ParseResult::ALM(parse_alm(nmea_sentence)?)),
+            SentenceType::APB => Ok(ParseResult::APB(parse_apb(nmea_sentence)?)),
+            SentenceType::BOD => Ok(ParseResult::BOD(parse_bod(nmea_sentence)?)),
             SentenceType::BWC => {
                 let data = parse_bwc(nmea_sentence)?;
                 Ok(ParseResult::BWC(data))
             }
+            SentenceType::BWR => Ok(ParseResult::BWR(parse_bwr(nmea_sentence)?)),
+            SentenceType::BWW => Ok(ParseResult::BWW(parse_bww(nmea_sentence)?)),
+            SentenceType::DBK => Ok(ParseResult::DBK(parse_dbk(nmea_sentence)?)),
+            SentenceType::DBS => Ok(ParseResult::DBS(parse_dbs(nmea_sentence)?)),
+            SentenceType::DBT => Ok(ParseResult::DBT(parse_dbt(nmea_sentence)?)),
+            SentenceType::DPT => Ok(ParseResult::DPT(parse_dpt(nmea_sentence)?)),
+            SentenceType::DTM => Ok(ParseResult::DTM(parse_dtm(nmea_sentence)?)),
+            SentenceType::GBS => Ok(ParseResult::GBS(parse_gbs(nmea_sentence)?)),
             SentenceType::GGA => {
                 let data = parse_gga(nmea_sentence)?;
                 Ok(ParseResult::GGA(data))
@@ -168,14 +319,48 @@ pub fn parse(xs: &[u8]) -> Result<ParseResult, NmeaError> {
                 let data = parse_gsv(nmea_sentence)?;
                 Ok(ParseResult::GSV(data))
             }
+            SentenceType::RMB => Ok(ParseResult::RMB(parse_rmb(nmea_sentence)?)),
             SentenceType::RMC => {
                 let data = parse_rmc(nmea_sentence)?;
                 Ok(ParseResult::RMC(data))
             }
+            SentenceType::ROT => Ok(ParseResult::ROT(parse_rot(nmea_sentence)?)),
+            SentenceType::RPM => Ok(ParseResult::RPM(parse_rpm(nmea_sentence)?)),
+            SentenceType::RSA => Ok(ParseResult::RSA(parse_rsa(nmea_sentence)?)),
+            SentenceType::RTE => Ok(ParseResult::RTE(parse_rte(nmea_sentence)?)),
             SentenceType::GSA => Ok(ParseResult::GSA(parse_gsa(nmea_sentence)?)),
+            SentenceType::GST => Ok(ParseResult::GST(parse_gst(nmea_sentence)?)),
+            SentenceType::HDG => Ok(ParseResult::HDG(parse_hdg(nmea_sentence)?)),
+            SentenceType::HDM => Ok(ParseResult::HDM(parse_hdm(nmea_sentence)?)),
+            SentenceType::HDT => Ok(ParseResult::HDT(parse_hdt(nmea_sentence)?)),
+            SentenceType::HMR => Ok(ParseResult::HMR(parse_hmr(nmea_sentence)?)),
+            SentenceType::HMS => Ok(ParseResult::HMS(parse_hms_sentence(nmea_sentence)?)),
+            SentenceType::MTW => Ok(ParseResult::MTW(parse_mtw(nmea_sentence)?)),
+            SentenceType::MWD => Ok(ParseResult::MWD(parse_mwd(nmea_sentence)?)),
+            SentenceType::MWV => Ok(ParseResult::MWV(parse_mwv(nmea_sentence)?)),
+            SentenceType::VBW => Ok(ParseResult::VBW(parse_vbw(nmea_sentence)?)),
+            SentenceType::VDM => Ok(ParseResult::VDM(parse_vdm(nmea_sentence)?)),
+            SentenceType::VDO => Ok(ParseResult::VDO(parse_vdo(nmea_sentence)?)),
+            SentenceType::VHW => Ok(ParseResult::VHW(parse_vhw(nmea_sentence)?)),
+            SentenceType::VLW => Ok(ParseResult::VLW(parse_vlw(nmea_sentence)?)),
+            SentenceType::VPW => Ok(ParseResult::VPW(parse_vpw(nmea_sentence)?)),
+            SentenceType::VWR => Ok(ParseResult::VWR(parse_vwr(nmea_sentence)?)),
             SentenceType::VTG => Ok(ParseResult::VTG(parse_vtg(nmea_sentence)?)),
+            SentenceType::WCV => Ok(ParseResult::WCV(parse_wcv(nmea_sentence)?)),
+            SentenceType::WNC => Ok(ParseResult::WNC(parse_wnc(nmea_sentence)?)),
+            SentenceType::WPL => Ok(ParseResult::WPL(parse_wpl(nmea_sentence)?)),
             SentenceType::GLL => Ok(ParseResult::GLL(parse_gll(nmea_sentence)?)),
+            SentenceType::GRS => Ok(ParseResult::GRS(parse_grs(nmea_sentence)?)),
+            SentenceType::GNS => Ok(ParseResult::GNS(parse_gns(nmea_sentence)?)),
+            SentenceType::TLL => Ok(ParseResult::TLL(parse_tll(nmea_sentence)?)),
+            SentenceType::TTM => Ok(ParseResult::TTM(parse_ttm(nmea_sentence)?)),
             SentenceType::TXT => Ok(ParseResult::TXT(parse_txt(nmea_sentence)?)),
+            SentenceType::XDR => Ok(ParseResult::XDR(parse_xdr(nmea_sentence)?)),
+            SentenceType::XTE => Ok(ParseResult::XTE(parse_xte(nmea_sentence)?)),
+            SentenceType::ZDA => Ok(ParseResult::ZDA(parse_zda(nmea_sentence)?)),
+            SentenceType::ZDL => Ok(ParseResult::ZDL(parse_zdl(nmea_sentence)?)),
+            SentenceType::ZFO => Ok(ParseResult::ZFO(parse_zfo(nmea_sentence)?)),
+            SentenceType::ZTG => Ok(ParseResult::ZTG(parse_ztg(nmea_sentence)?)),
             msg_id => Ok(ParseResult::Unsupported(msg_id)),
         }
     } else {
@@ -0,0 +1,97 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct AamData {
+    pub arrival_circle_entered: bool,
+    pub perpendicular_passed: bool,
+    /// Arrival circle radius, nautical miles
+    pub arrival_circle_radius: Option<f32>,
+    pub waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+fn do_parse_aam(i: &[u8]) -> Result<AamData, NmeaError<'_>> {
+    // 1. Status, A = Arrival Circle Entered
+    let (i, arrival_circle_entered) = opt(one_of("AV"))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Status, A = Perpendicular passed at waypoint
+    let (i, perpendicular_passed) = opt(one_of("AV"))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Arrival circle radius
+    let (i, arrival_circle_radius) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Units of radius, N = Nautical miles, K = Kilometers
+    let (i, radius_units) = opt(one_of("NK"))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Waypoint ID
+    let (_i, waypoint_id) = parse_str_field(i)?;
+
+    Ok(AamData {
+        arrival_circle_entered: arrival_circle_entered == Some('A'),
+        perpendicular_passed: perpendicular_passed == Some('A'),
+        arrival_circle_radius: arrival_circle_radius.map(|radius| match radius_units {
+            Some('K') => radius / 1.852,
+            _ => radius,
+        }),
+        waypoint_id: array_string(waypoint_id)?,
+    })
+}
+
+/// Parse AAM message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_aam_waypoint_arrival_alarm
+///
+/// $--AAM,A,A,x.x,N,c--c*hh<CR><LF>
+///
+/// Arrival circle radius is normalised to nautical miles.
+pub fn parse_aam(sentence: NmeaSentence) -> Result<AamData, NmeaError> {
+    if sentence.message_id != b"AAM" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"AAM",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_aam(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_aam() {
+        let sentence = parse_nmea_sentence(b"$GPAAM,A,A,0.10,N,WPTNME*32").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            AamData {
+                arrival_circle_entered: true,
+                perpendicular_passed: true,
+                arrival_circle_radius: Some(0.1),
+                waypoint_id: Some(ArrayString::from("WPTNME").unwrap()),
+            },
+            parse_aam(sentence).unwrap()
+        );
+
+        let sentence = parse_nmea_sentence(b"$GPAAM,V,V,0.5,K,WPTNME*03").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_aam(sentence).unwrap();
+
+        assert!(!data.arrival_circle_entered);
+        assert!(!data.perpendicular_passed);
+        assert_relative_eq!(data.arrival_circle_radius.unwrap(), 0.5 / 1.852);
+    }
+}
@@ -0,0 +1,602 @@
+use arrayvec::{Array, ArrayString};
+
+use crate::NmeaError;
+
+/// Navigation status of a vessel sending a class A position report
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AisNavigationStatus {
+    UnderWayUsingEngine,
+    AtAnchor,
+    NotUnderCommand,
+    RestrictedManoeuverability,
+    ConstrainedByDraught,
+    Moored,
+    Aground,
+    EngagedInFishing,
+    UnderWaySailing,
+    AisSartActive,
+    NotDefined,
+    Reserved(u8),
+}
+
+impl From<u32> for AisNavigationStatus {
+    fn from(status: u32) -> Self {
+        match status {
+            0 => AisNavigationStatus::UnderWayUsingEngine,
+            1 => AisNavigationStatus::AtAnchor,
+            2 => AisNavigationStatus::NotUnderCommand,
+            3 => AisNavigationStatus::RestrictedManoeuverability,
+            4 => AisNavigationStatus::ConstrainedByDraught,
+            5 => AisNavigationStatus::Moored,
+            6 => AisNavigationStatus::Aground,
+            7 => AisNavigationStatus::EngagedInFishing,
+            8 => AisNavigationStatus::UnderWaySailing,
+            14 => AisNavigationStatus::AisSartActive,
+            15 => AisNavigationStatus::NotDefined,
+            status => AisNavigationStatus::Reserved(status as u8),
+        }
+    }
+}
+
+/// Distances from the position reference point to the vessel sides, meters
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AisDimensions {
+    pub to_bow: u16,
+    pub to_stern: u16,
+    pub to_port: u8,
+    pub to_starboard: u8,
+}
+
+/// Class A position report, message types 1, 2 and 3.
+///
+/// Speeds are in knots, angles in degrees and the rate of turn in degrees
+/// per minute, positive to starboard.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AisPositionReport {
+    pub message_type: u8,
+    pub mmsi: u32,
+    pub navigation_status: AisNavigationStatus,
+    pub rate_of_turn: Option<f32>,
+    pub speed_over_ground: Option<f32>,
+    pub position_accuracy: bool,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub course_over_ground: Option<f32>,
+    pub true_heading: Option<u16>,
+    /// Second of the UTC minute of the report
+    pub timestamp: Option<u8>,
+    pub raim: bool,
+}
+
+/// Class A static and voyage related data, message type 5
+#[derive(Debug, PartialEq, Clone)]
+pub struct AisStaticAndVoyageData {
+    pub mmsi: u32,
+    pub imo_number: Option<u32>,
+    pub callsign: ArrayString<[u8; 7]>,
+    pub vessel_name: ArrayString<[u8; 20]>,
+    pub ship_type: u8,
+    pub dimensions: AisDimensions,
+    pub epfd_type: u8,
+    pub eta_month: Option<u8>,
+    pub eta_day: Option<u8>,
+    pub eta_hour: Option<u8>,
+    pub eta_minute: Option<u8>,
+    /// Maximum present static draught, meters
+    pub draught: Option<f32>,
+    pub destination: ArrayString<[u8; 20]>,
+    pub dte_available: bool,
+}
+
+/// Class B position report, message type 18
+#[derive(Debug, PartialEq, Clone)]
+pub struct AisClassBPositionReport {
+    pub mmsi: u32,
+    pub speed_over_ground: Option<f32>,
+    pub position_accuracy: bool,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub course_over_ground: Option<f32>,
+    pub true_heading: Option<u16>,
+    pub timestamp: Option<u8>,
+    pub raim: bool,
+}
+
+/// Extended class B position report, message type 19
+#[derive(Debug, PartialEq, Clone)]
+pub struct AisClassBExtendedPositionReport {
+    pub mmsi: u32,
+    pub speed_over_ground: Option<f32>,
+    pub position_accuracy: bool,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+    pub course_over_ground: Option<f32>,
+    pub true_heading: Option<u16>,
+    pub timestamp: Option<u8>,
+    pub vessel_name: ArrayString<[u8; 20]>,
+    pub ship_type: u8,
+    pub dimensions: AisDimensions,
+    pub epfd_type: u8,
+    pub raim: bool,
+}
+
+/// One of the two parts of a static data report
+#[derive(Debug, PartialEq, Clone)]
+pub enum AisStaticDataPart {
+    A {
+        vessel_name: ArrayString<[u8; 20]>,
+    },
+    B {
+        ship_type: u8,
+        vendor_id: ArrayString<[u8; 3]>,
+        model: u8,
+        serial: u32,
+        callsign: ArrayString<[u8; 7]>,
+        /// None for auxiliary craft, which report their mothership instead
+        dimensions: Option<AisDimensions>,
+        mothership_mmsi: Option<u32>,
+    },
+}
+
+/// Class B static data report, message type 24
+#[derive(Debug, PartialEq, Clone)]
+pub struct AisStaticDataReport {
+    pub mmsi: u32,
+    pub part: AisStaticDataPart,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AisMessage {
+    PositionReport(AisPositionReport),
+    StaticAndVoyageData(AisStaticAndVoyageData),
+    ClassBPositionReport(AisClassBPositionReport),
+    ClassBExtendedPositionReport(AisClassBExtendedPositionReport),
+    StaticDataReport(AisStaticDataReport),
+    /// A valid payload of a message type that is not decoded
+    Unsupported(u8),
+}
+
+/// Bits of a de-armored payload, reading past the end gives zeros
+struct PayloadBits {
+    sextets: Vec<u8>,
+    len: usize,
+}
+
+impl PayloadBits {
+    fn new(payload: &str, fill_bits: u8) -> Result<PayloadBits, NmeaError<'static>> {
+        let sextets = payload
+            .bytes()
+            .map(|c| match c {
+                b'0'..=b'W' => Ok(c - b'0'),
+                b'`'..=b'w' => Ok(c - b'0' - 8),
+                _ => Err(NmeaError::InvalidAisPayload),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        let len = (sextets.len() * 6)
+            .checked_sub(usize::from(fill_bits))
+            .ok_or(NmeaError::InvalidAisPayload)?;
+        Ok(PayloadBits { sextets, len })
+    }
+
+    fn bit(&self, n: usize) -> u32 {
+        if n < self.len {
+            u32::from(self.sextets[n / 6] >> (5 - n % 6)) & 1
+        } else {
+            0
+        }
+    }
+
+    fn flag(&self, n: usize) -> bool {
+        self.bit(n) == 1
+    }
+
+    fn uint(&self, start: usize, len: usize) -> u32 {
+        (start..start + len).fold(0, |value, n| value << 1 | self.bit(n))
+    }
+
+    fn int(&self, start: usize, len: usize) -> i32 {
+        ((self.uint(start, len) << (32 - len)) as i32) >> (32 - len)
+    }
+
+    /// Six bit ASCII text, with the `@` and space padding removed
+    fn text<A: Array<Item = u8> + Copy>(&self, start: usize, len: usize) -> ArrayString<A> {
+        let mut text = ArrayString::new();
+        for n in (start..start + len).step_by(6) {
+            let c = self.uint(n, 6) as u8;
+            text.push(if c < 32 { c + 64 } else { c } as char);
+        }
+        let trimmed_len = text.trim_end_matches(['@', ' ']).len();
+        text.truncate(trimmed_len);
+        text
+    }
+
+    fn longitude(&self, start: usize) -> Option<f64> {
+        let lon = self.int(start, 28);
+        if lon.abs() <= 180 * 600_000 {
+            Some(f64::from(lon) / 600_000.)
+        } else {
+            None
+        }
+    }
+
+    fn latitude(&self, start: usize) -> Option<f64> {
+        let lat = self.int(start, 27);
+        if lat.abs() <= 90 * 600_000 {
+            Some(f64::from(lat) / 600_000.)
+        } else {
+            None
+        }
+    }
+
+    fn speed(&self, start: usize) -> Option<f32> {
+        match self.uint(start, 10) {
+            1023 => None,
+            speed => Some(speed as f32 / 10.),
+        }
+    }
+
+    fn course(&self, start: usize) -> Option<f32> {
+        match self.uint(start, 12) {
+            course if course < 3600 => Some(course as f32 / 10.),
+            _ => None,
+        }
+    }
+
+    fn heading(&self, start: usize) -> Option<u16> {
+        match self.uint(start, 9) {
+            heading if heading < 360 => Some(heading as u16),
+            _ => None,
+        }
+    }
+
+    fn timestamp(&self, start: usize) -> Option<u8> {
+        match self.uint(start, 6) {
+            second if second < 60 => Some(second as u8),
+            _ => None,
+        }
+    }
+
+    fn dimensions(&self, start: usize) -> AisDimensions {
+        AisDimensions {
+            to_bow: self.uint(start, 9) as u16,
+            to_stern: self.uint(start + 9, 9) as u16,
+            to_port: self.uint(start + 18, 6) as u8,
+            to_starboard: self.uint(start + 24, 6) as u8,
+        }
+    }
+
+    fn require(&self, len: usize) -> Result<(), NmeaError<'static>> {
+        if self.len < len {
+            Err(NmeaError::InvalidAisPayload)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn decode_rate_of_turn(rot: i32) -> Option<f32> {
+    // -128 is not available, +-127 only gives the direction of the turn
+    if rot.abs() >= 127 {
+        None
+    } else {
+        let rate = (rot as f32 / 4.733).powi(2);
+        Some(if rot < 0 { -rate } else { rate })
+    }
+}
+
+fn decode_position_report(bits: &PayloadBits) -> Result<AisPositionReport, NmeaError<'static>> {
+    bits.require(168)?;
+    Ok(AisPositionReport {
+        message_type: bits.uint(0, 6) as u8,
+        mmsi: bits.uint(8, 30),
+        navigation_status: bits.uint(38, 4).into(),
+        rate_of_turn: decode_rate_of_turn(bits.int(42, 8)),
+        speed_over_ground: bits.speed(50),
+        position_accuracy: bits.flag(60),
+        longitude: bits.longitude(61),
+        latitude: bits.latitude(89),
+        course_over_ground: bits.course(116),
+        true_heading: bits.heading(128),
+        timestamp: bits.timestamp(137),
+        raim: bits.flag(148),
+    })
+}
+
+fn decode_static_and_voyage_data(
+    bits: &PayloadBits,
+) -> Result<AisStaticAndVoyageData, NmeaError<'static>> {
+    // Some transmitters leave out the two spare bits at the end
+    bits.require(422)?;
+    Ok(AisStaticAndVoyageData {
+        mmsi: bits.uint(8, 30),
+        imo_number: match bits.uint(40, 30) {
+            0 => None,
+            imo => Some(imo),
+        },
+        callsign: bits.text(70, 42),
+        vessel_name: bits.text(112, 120),
+        ship_type: bits.uint(232, 8) as u8,
+        dimensions: bits.dimensions(240),
+        epfd_type: bits.uint(270, 4) as u8,
+        eta_month: match bits.uint(274, 4) {
+            month @ 1..=12 => Some(month as u8),
+            _ => None,
+        },
+        eta_day: match bits.uint(278, 5) {
+            0 => None,
+            day => Some(day as u8),
+        },
+        eta_hour: match bits.uint(283, 5) {
+            hour if hour < 24 => Some(hour as u8),
+            _ => None,
+        },
+        eta_minute: match bits.uint(288, 6) {
+            minute if minute < 60 => Some(minute as u8),
+            _ => None,
+        },
+        draught: match bits.uint(294, 8) {
+            0 => None,
+            draught => Some(draught as f32 / 10.),
+        },
+        destination: bits.text(302, 120),
+        dte_available: !bits.flag(422),
+    })
+}
+
+fn decode_class_b_position_report(
+    bits: &PayloadBits,
+) -> Result<AisClassBPositionReport, NmeaError<'static>> {
+    bits.require(168)?;
+    Ok(AisClassBPositionReport {
+        mmsi: bits.uint(8, 30),
+        speed_over_ground: bits.speed(46),
+        position_accuracy: bits.flag(56),
+        longitude: bits.longitude(57),
+        latitude: bits.latitude(85),
+        course_over_ground: bits.course(112),
+        true_heading: bits.heading(124),
+        timestamp: bits.timestamp(133),
+        raim: bits.flag(147),
+    })
+}
+
+fn decode_class_b_extended_position_report(
+    bits: &PayloadBits,
+) -> Result<AisClassBExtendedPositionReport, NmeaError<'static>> {
+    bits.require(312)?;
+    Ok(AisClassBExtendedPositionReport {
+        mmsi: bits.uint(8, 30),
+        speed_over_ground: bits.speed(46),
+        position_accuracy: bits.flag(56),
+        longitude: bits.longitude(57),
+        latitude: bits.latitude(85),
+        course_over_ground: bits.course(112),
+        true_heading: bits.heading(124),
+        timestamp: bits.timestamp(133),
+        vessel_name: bits.text(143, 120),
+        ship_type: bits.uint(263, 8) as u8,
+        dimensions: bits.dimensions(271),
+        epfd_type: bits.uint(301, 4) as u8,
+        raim: bits.flag(305),
+    })
+}
+
+fn decode_static_data_report(
+    bits: &PayloadBits,
+) -> Result<AisStaticDataReport, NmeaError<'static>> {
+    bits.require(160)?;
+    let mmsi = bits.uint(8, 30);
+    let part = match bits.uint(38, 2) {
+        0 => AisStaticDataPart::A {
+            vessel_name: bits.text(40, 120),
+        },
+        1 => {
+            bits.require(168)?;
+            // MMSIs of auxiliary craft are of the form 98XXXYYYY
+            let auxiliary = mmsi / 10_000_000 == 98;
+            AisStaticDataPart::B {
+                ship_type: bits.uint(40, 8) as u8,
+                vendor_id: bits.text(48, 18),
+                model: bits.uint(66, 4) as u8,
+                serial: bits.uint(70, 20),
+                callsign: bits.text(90, 42),
+                dimensions: if auxiliary {
+                    None
+                } else {
+                    Some(bits.dimensions(132))
+                },
+                mothership_mmsi: if auxiliary {
+                    Some(bits.uint(132, 30))
+                } else {
+                    None
+                },
+            }
+        }
+        _ => return Err(NmeaError::InvalidAisPayload),
+    };
+    Ok(AisStaticDataReport { mmsi, part })
+}
+
+/// Decode the armored payload of an AIS message, for messages split over
+/// several VDM or VDO sentences the payloads of all the fragments have to be
+/// concatenated first.
+pub fn decode_ais_payload(payload: &str, fill_bits: u8) -> Result<AisMessage, NmeaError<'static>> {
+    let bits = PayloadBits::new(payload, fill_bits)?;
+    bits.require(6)?;
+    match bits.uint(0, 6) as u8 {
+        1..=3 => Ok(AisMessage::PositionReport(decode_position_report(&bits)?)),
+        5 => Ok(AisMessage::StaticAndVoyageData(
+            decode_static_and_voyage_data(&bits)?,
+        )),
+        18 => Ok(AisMessage::ClassBPositionReport(
+            decode_class_b_position_report(&bits)?,
+        )),
+        19 => Ok(AisMessage::ClassBExtendedPositionReport(
+            decode_class_b_extended_position_report(&bits)?,
+        )),
+        24 => Ok(AisMessage::StaticDataReport(decode_static_data_report(
+            &bits,
+        )?)),
+        message_type => Ok(AisMessage::Unsupported(message_type)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_decode_position_report() {
+        let message = decode_ais_payload("15M67FC000G?ufbE`FepT@3n00Sa", 0).unwrap();
+        let report = match message {
+            AisMessage::PositionReport(report) => report,
+            _ => panic!("Unexpected message {:?}", message),
+        };
+        assert_eq!(report.message_type, 1);
+        assert_eq!(report.mmsi, 366053209);
+        assert_eq!(
+            report.navigation_status,
+            AisNavigationStatus::RestrictedManoeuverability
+        );
+        assert_eq!(report.rate_of_turn, Some(0.));
+        assert_eq!(report.speed_over_ground, Some(0.));
+        assert!(!report.position_accuracy);
+        assert_relative_eq!(report.longitude.unwrap(), -122.341618, epsilon = 1e-6);
+        assert_relative_eq!(report.latitude.unwrap(), 37.802118, epsilon = 1e-6);
+        assert_relative_eq!(report.course_over_ground.unwrap(), 219.3);
+        assert_eq!(report.true_heading, Some(1));
+        assert_eq!(report.timestamp, Some(59));
+        assert!(!report.raim);
+    }
+
+    #[test]
+    fn test_decode_static_and_voyage_data() {
+        let message = decode_ais_payload(
+            "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp888888888880",
+            2,
+        )
+        .unwrap();
+        let data = match message {
+            AisMessage::StaticAndVoyageData(data) => data,
+            _ => panic!("Unexpected message {:?}", message),
+        };
+        assert_eq!(data.mmsi, 351759000);
+        assert_eq!(data.imo_number, Some(9134270));
+        assert_eq!(&data.callsign, "3FOF8");
+        assert_eq!(&data.vessel_name, "EVER DIADEM");
+        assert_eq!(data.ship_type, 70);
+        assert_eq!(
+            data.dimensions,
+            AisDimensions {
+                to_bow: 225,
+                to_stern: 70,
+                to_port: 1,
+                to_starboard: 31,
+            }
+        );
+        assert_eq!(data.epfd_type, 1);
+        assert_eq!(data.eta_month, Some(5));
+        assert_eq!(data.eta_day, Some(15));
+        assert_eq!(data.eta_hour, Some(14));
+        assert_eq!(data.eta_minute, Some(0));
+        assert_relative_eq!(data.draught.unwrap(), 12.2);
+        assert_eq!(&data.destination, "NEW YORK");
+        assert!(data.dte_available);
+    }
+
+    #[test]
+    fn test_decode_class_b() {
+        let message = decode_ais_payload("B52K>;h00Fc>jpUlNV@ikwpUoP06", 0).unwrap();
+        assert_eq!(
+            message,
+            AisMessage::ClassBPositionReport(AisClassBPositionReport {
+                mmsi: 338087471,
+                speed_over_ground: Some(0.1),
+                position_accuracy: false,
+                longitude: Some(-44_443_279. / 600_000.),
+                latitude: Some(24_410_724. / 600_000.),
+                course_over_ground: Some(79.6),
+                true_heading: None,
+                timestamp: Some(49),
+                raim: true,
+            })
+        );
+
+        let message =
+            decode_ais_payload("C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220", 0).unwrap();
+        let report = match message {
+            AisMessage::ClassBExtendedPositionReport(report) => report,
+            _ => panic!("Unexpected message {:?}", message),
+        };
+        assert_eq!(report.mmsi, 367059850);
+        assert_relative_eq!(report.speed_over_ground.unwrap(), 8.7);
+        assert_relative_eq!(report.latitude.unwrap(), 29.543695, epsilon = 1e-6);
+        assert_relative_eq!(report.longitude.unwrap(), -88.810392, epsilon = 1e-6);
+        assert_relative_eq!(report.course_over_ground.unwrap(), 335.9);
+        assert_eq!(report.true_heading, None);
+        assert_eq!(&report.vessel_name, "CAPT.J.RIMES");
+        assert_eq!(report.ship_type, 70);
+        assert_eq!(
+            report.dimensions,
+            AisDimensions {
+                to_bow: 5,
+                to_stern: 21,
+                to_port: 4,
+                to_starboard: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_static_data_report() {
+        let message = decode_ais_payload("H42O55i18tMET00000000000000", 2).unwrap();
+        assert_eq!(
+            message,
+            AisMessage::StaticDataReport(AisStaticDataReport {
+                mmsi: 271041815,
+                part: AisStaticDataPart::A {
+                    vessel_name: ArrayString::from("PROGUY").unwrap(),
+                },
+            })
+        );
+
+        let message = decode_ais_payload("H42O55lti4hhhilD3nink000?050", 0).unwrap();
+        assert_eq!(
+            message,
+            AisMessage::StaticDataReport(AisStaticDataReport {
+                mmsi: 271041815,
+                part: AisStaticDataPart::B {
+                    ship_type: 60,
+                    vendor_id: ArrayString::from("1D0").unwrap(),
+                    model: 12,
+                    serial: 199796,
+                    callsign: ArrayString::from("TC6163").unwrap(),
+                    dimensions: Some(AisDimensions {
+                        to_bow: 0,
+                        to_stern: 15,
+                        to_port: 0,
+                        to_starboard: 5,
+                    }),
+                    mothership_mmsi: None,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_payload() {
+        assert_eq!(
+            decode_ais_payload("15M67FC000G?", 0),
+            Err(NmeaError::InvalidAisPayload)
+        );
+        assert_eq!(
+            decode_ais_payload("15M67FC000G?ufbE`FepT@3n00S~", 0),
+            Err(NmeaError::InvalidAisPayload)
+        );
+        assert_eq!(
+            decode_ais_payload("85M67FC000G?ufbE`FepT@3n00Sa", 0),
+            Ok(AisMessage::Unsupported(8))
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use core::f64::consts::PI;
+use core::str;
+
+use nom::character::complete::{char, hex_digit1};
+use nom::combinator::{map_res, opt};
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::number;
+use crate::NmeaError;
+
+/// GPS almanac of one satellite, angles are in radians
+#[derive(Debug, PartialEq, Clone)]
+pub struct AlmData {
+    pub total_sentences: u16,
+    pub sentence_num: u16,
+    pub prn: u8,
+    pub week: Option<u16>,
+    pub health: Option<u8>,
+    pub eccentricity: Option<f64>,
+    /// Almanac reference time, seconds of the GPS week
+    pub reference_time: Option<u32>,
+    pub inclination: Option<f64>,
+    /// Rate of right ascension, radians per second
+    pub right_ascension_rate: Option<f64>,
+    /// Square root of the semi-major axis, square root of meters
+    pub semi_major_axis_sqrt: Option<f64>,
+    pub argument_of_perigee: Option<f64>,
+    pub longitude_of_ascending_node: Option<f64>,
+    pub mean_anomaly: Option<f64>,
+    /// Clock bias, seconds
+    pub clock_bias: Option<f64>,
+    /// Clock drift, seconds per second
+    pub clock_drift: Option<f64>,
+}
+
+fn hex_field(i: &[u8]) -> IResult<&[u8], Option<u32>> {
+    opt(map_res(hex_digit1, |data: &[u8]| {
+        u32::from_str_radix(unsafe { str::from_utf8_unchecked(data) }, 16)
+    }))(i)
+}
+
+/// Two's complement value of the `bits` low bits
+fn signed(value: u32, bits: u32) -> f64 {
+    let shift = 32 - bits;
+    f64::from(((value << shift) as i32) >> shift)
+}
+
+fn do_parse_alm(i: &[u8]) -> IResult<&[u8], AlmData> {
+    let (i, total_sentences) = number::<u16>(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, sentence_num) = number::<u16>(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, prn) = number::<u8>(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, week) = opt(number::<u16>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, health) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, eccentricity) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, reference_time) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, inclination) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, right_ascension_rate) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, semi_major_axis_sqrt) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, argument_of_perigee) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, longitude_of_ascending_node) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, mean_anomaly) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, clock_bias) = hex_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, clock_drift) = hex_field(i)?;
+
+    // Scale factors of IS-GPS-200, angles are given in semicircles
+    Ok((
+        i,
+        AlmData {
+            total_sentences,
+            sentence_num,
+            prn,
+            week,
+            health: health.map(|health| health as u8),
+            eccentricity: eccentricity.map(|e| f64::from(e) * 2f64.powi(-21)),
+            reference_time: reference_time.map(|toa| toa << 12),
+            // Offset from the reference inclination of 0.3 semicircles
+            inclination: inclination.map(|di| (0.3 + signed(di, 16) * 2f64.powi(-19)) * PI),
+            right_ascension_rate: right_ascension_rate
+                .map(|rate| signed(rate, 16) * 2f64.powi(-38) * PI),
+            semi_major_axis_sqrt: semi_major_axis_sqrt.map(|a| f64::from(a) * 2f64.powi(-11)),
+            argument_of_perigee: argument_of_perigee
+                .map(|omega| signed(omega, 24) * 2f64.powi(-23) * PI),
+            longitude_of_ascending_node: longitude_of_ascending_node
+                .map(|omega0| signed(omega0, 24) * 2f64.powi(-23) * PI),
+            mean_anomaly: mean_anomaly.map(|m0| signed(m0, 24) * 2f64.powi(-23) * PI),
+            clock_bias: clock_bias.map(|af0| signed(af0, 11) * 2f64.powi(-20)),
+            clock_drift: clock_drift.map(|af1| signed(af1, 11) * 2f64.powi(-38)),
+        },
+    ))
+}
+
+/// Parse ALM message
+/// from gpsd:
+/// $GPALM,1,1,15,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001*77
+/// 1     1       Total number of messages
+/// 2     1       Message Number
+/// 3     15      Satellite PRN number
+/// 4     1159    GPS Week Number
+/// 5     00      SV health, bits 17-24 of each almanac page
+/// 6     441d    Eccentricity
+/// 7     4e      Almanac Reference Time
+/// 8     16be    Inclination Angle
+/// 9     fd5e    Rate of Right Ascension
+/// 10    a10c9f  Root of semi-major axis
+/// 11    4a2da4  Argument of perigee
+/// 12    686e81  Longitude of ascension node
+/// 13    58cbe1  Mean anomaly
+/// 14    0a4     F0 Clock Parameter
+/// 15    001     F1 Clock Parameter
+///
+/// The hex fields are decoded into SI units, with angles in radians.
+pub fn parse_alm(sentence: NmeaSentence) -> Result<AlmData, NmeaError> {
+    if sentence.message_id != b"ALM" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"ALM",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_alm(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_alm() {
+        let s = parse_nmea_sentence(
+            b"$GPALM,1,1,15,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001*77",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let alm = parse_alm(s).unwrap();
+        assert_eq!(alm.total_sentences, 1);
+        assert_eq!(alm.sentence_num, 1);
+        assert_eq!(alm.prn, 15);
+        assert_eq!(alm.week, Some(1159));
+        assert_eq!(alm.health, Some(0));
+        assert_relative_eq!(alm.eccentricity.unwrap(), 0.00831460952758789);
+        assert_eq!(alm.reference_time, Some(319488));
+        assert_relative_eq!(alm.inclination.unwrap(), 0.9773638747764308);
+        assert_relative_eq!(alm.right_ascension_rate.unwrap(), -7.703178011141137e-9);
+        assert_relative_eq!(alm.semi_major_axis_sqrt.unwrap(), 5153.57763671875);
+        assert_relative_eq!(alm.argument_of_perigee.unwrap(), 1.8206089929751674);
+        assert_relative_eq!(alm.longitude_of_ascending_node.unwrap(), 2.5631384603650704);
+        assert_relative_eq!(alm.mean_anomaly.unwrap(), 2.1793915946706477);
+        assert_relative_eq!(alm.clock_bias.unwrap(), 1.56402587890625e-4);
+        assert_relative_eq!(alm.clock_drift.unwrap(), 3.637978807091713e-12);
+    }
+
+    #[test]
+    fn test_parse_alm_negative_clock() {
+        let s = parse_nmea_sentence(
+            b"$GPALM,2,1,01,2210,00,0cf5,90,0a3b,fd3f,a10d2f,6b6c7e,ab3b6c,3c1a0e,7f3,ffe*70",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let alm = parse_alm(s).unwrap();
+        assert_relative_eq!(alm.clock_bias.unwrap(), -13. * 2f64.powi(-20));
+        assert_relative_eq!(alm.clock_drift.unwrap(), -2. * 2f64.powi(-38));
+    }
+
+    #[test]
+    fn test_parse_alm_empty() {
+        let s = parse_nmea_sentence(b"$GPALM,2,2,02,,,,,,,,,,,,*79").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let alm = parse_alm(s).unwrap();
+        assert_eq!(alm.prn, 2);
+        assert_eq!(alm.week, None);
+        assert_eq!(alm.mean_anomaly, None);
+    }
+}
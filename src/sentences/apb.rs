@@ -0,0 +1,178 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::rmb::{parse_steer_direction, SteerDirection};
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+/// Bearing or heading in degrees, with the north it is relative to
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ApbBearing {
+    True(f32),
+    Magnetic(f32),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ApbData {
+    pub valid: bool,
+    /// Cross track error, nautical miles
+    pub cross_track_error: Option<f32>,
+    pub steer_direction: Option<SteerDirection>,
+    pub arrival_circle_entered: bool,
+    pub perpendicular_passed: bool,
+    pub bearing_origin_to_destination: Option<ApbBearing>,
+    pub destination_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub bearing_to_destination: Option<ApbBearing>,
+    pub heading_to_steer: Option<ApbBearing>,
+}
+
+fn parse_bearing(i: &[u8]) -> IResult<&[u8], Option<ApbBearing>> {
+    let (i, value) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, reference) = opt(one_of("MT"))(i)?;
+    Ok((
+        i,
+        value.map(|value| match reference {
+            Some('M') => ApbBearing::Magnetic(value),
+            _ => ApbBearing::True(value),
+        }),
+    ))
+}
+
+fn do_parse_apb(i: &[u8]) -> Result<ApbData, NmeaError<'_>> {
+    // 1. Status, V = Loran-C Blink or SNR warning, A = OK
+    let (i, status1) = one_of("AV")(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Status, V = Loran-C Cycle Lock warning flag, A = OK
+    let (i, status2) = one_of("AV")(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 3. Cross Track Error Magnitude
+    let (i, cross_track_error) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Direction to steer, L or R
+    let (i, steer_direction) = parse_steer_direction(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Cross Track Units, N = Nautical miles, K = Kilometers
+    let (i, xte_units) = opt(one_of("NK"))(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 6. Status, A = Arrival Circle Entered
+    let (i, arrival_circle_entered) = opt(one_of("AV"))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Status, A = Perpendicular passed at waypoint
+    let (i, perpendicular_passed) = opt(one_of("AV"))(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 8. Bearing origin to destination
+    // 9. M = Magnetic, T = True
+    let (i, bearing_origin_to_destination) = parse_bearing(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 10. Destination Waypoint ID
+    let (i, destination_waypoint_id) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 11. Bearing, present position to Destination
+    // 12. M = Magnetic, T = True
+    let (i, bearing_to_destination) = parse_bearing(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 13. Heading to steer to destination waypoint
+    // 14. M = Magnetic, T = True
+    let (_i, heading_to_steer) = parse_bearing(i)?;
+
+    // 15. FAA mode indicator (NMEA 2.3 and later, optional)
+
+    Ok(ApbData {
+        valid: status1 == 'A' && status2 == 'A',
+        cross_track_error: cross_track_error.map(|xte| match xte_units {
+            Some('K') => xte / 1.852,
+            _ => xte,
+        }),
+        steer_direction,
+        arrival_circle_entered: arrival_circle_entered == Some('A'),
+        perpendicular_passed: perpendicular_passed == Some('A'),
+        bearing_origin_to_destination,
+        destination_waypoint_id: array_string(destination_waypoint_id)?,
+        bearing_to_destination,
+        heading_to_steer,
+    })
+}
+
+/// Parse APB message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_apb_autopilot_sentence_b
+///
+/// $--APB,A,A,x.x,a,N,A,A,x.x,a,c--c,x.x,a,x.x,a*hh<CR><LF>
+///
+/// Cross track error is normalised to nautical miles.
+pub fn parse_apb(sentence: NmeaSentence) -> Result<ApbData, NmeaError> {
+    if sentence.message_id != b"APB" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"APB",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_apb(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_apb_full() {
+        let sentence =
+            parse_nmea_sentence(b"$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_apb(sentence).unwrap();
+
+        assert!(data.valid);
+        assert_relative_eq!(data.cross_track_error.unwrap(), 0.1);
+        assert_eq!(data.steer_direction, Some(SteerDirection::Right));
+        assert!(!data.arrival_circle_entered);
+        assert!(!data.perpendicular_passed);
+        assert_eq!(
+            data.bearing_origin_to_destination,
+            Some(ApbBearing::Magnetic(11.))
+        );
+        assert_eq!(&data.destination_waypoint_id.unwrap(), "DEST");
+        assert_eq!(data.bearing_to_destination, Some(ApbBearing::Magnetic(11.)));
+        assert_eq!(data.heading_to_steer, Some(ApbBearing::Magnetic(11.)));
+    }
+
+    #[test]
+    fn test_parse_apb_with_optional_fields() {
+        let sentence = parse_nmea_sentence(b"$GPAPB,A,A,,,N,A,A,,,,,,,*0A").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_apb(sentence).unwrap();
+
+        assert_eq!(
+            ApbData {
+                valid: true,
+                cross_track_error: None,
+                steer_direction: None,
+                arrival_circle_entered: true,
+                perpendicular_passed: true,
+                bearing_origin_to_destination: None,
+                destination_waypoint_id: None,
+                bearing_to_destination: None,
+                heading_to_steer: None,
+            },
+            data
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use arrayvec::ArrayString;
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct BodData {
+    /// Bearing from origin to destination, degrees True
+    pub true_bearing: Option<f32>,
+    /// Bearing from origin to destination, degrees Magnetic
+    pub magnetic_bearing: Option<f32>,
+    pub destination_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub origin_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+type BearingsAndWaypoints<'a> = (Option<f32>, Option<f32>, Option<&'a str>, Option<&'a str>);
+
+/// Parse the true and magnetic bearing fields followed by two waypoint IDs,
+/// shared with BWW
+pub(crate) fn parse_bearings_and_waypoints(i: &[u8]) -> IResult<&[u8], BearingsAndWaypoints<'_>> {
+    let (i, true_bearing) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('T'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, magnetic_bearing) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, first_waypoint_id) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, second_waypoint_id) = parse_str_field(i)?;
+    Ok((
+        i,
+        (
+            true_bearing,
+            magnetic_bearing,
+            first_waypoint_id,
+            second_waypoint_id,
+        ),
+    ))
+}
+
+fn do_parse_bod(i: &[u8]) -> Result<BodData, NmeaError<'_>> {
+    let (_i, (true_bearing, magnetic_bearing, destination_waypoint_id, origin_waypoint_id)) =
+        parse_bearings_and_waypoints(i)?;
+    Ok(BodData {
+        true_bearing,
+        magnetic_bearing,
+        destination_waypoint_id: array_string(destination_waypoint_id)?,
+        origin_waypoint_id: array_string(origin_waypoint_id)?,
+    })
+}
+
+/// Parse BOD message
+/// from gpsd:
+/// $--BOD,x.x,T,x.x,M,c--c,c--c*hh
+/// 1     x.x   Bearing Degrees, True
+/// 2     T     T = True
+/// 3     x.x   Bearing Degrees, Magnetic
+/// 4     M     M = Magnetic
+/// 5     c--c  Destination Waypoint
+/// 6     c--c  Origin Waypoint
+pub fn parse_bod(sentence: NmeaSentence) -> Result<BodData, NmeaError> {
+    if sentence.message_id != b"BOD" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"BOD",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_bod(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_bod() {
+        let sentence = parse_nmea_sentence(b"$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_bod(sentence).unwrap();
+
+        assert_eq!(data.true_bearing, Some(99.3));
+        assert_eq!(data.magnetic_bearing, Some(105.6));
+        assert_eq!(&data.destination_waypoint_id.unwrap(), "POINTB");
+        assert_eq!(&data.origin_waypoint_id.unwrap(), "POINTA");
+    }
+
+    #[test]
+    fn test_parse_bod_empty() {
+        let sentence = parse_nmea_sentence(b"$GPBOD,,T,,M,,*47").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            BodData {
+                true_bearing: None,
+                magnetic_bearing: None,
+                destination_waypoint_id: None,
+                origin_waypoint_id: None,
+            },
+            parse_bod(sentence).unwrap()
+        );
+    }
+}
@@ -1,12 +1,11 @@
 use arrayvec::ArrayString;
 use chrono::NaiveTime;
-use nom::bytes::complete::is_not;
 use nom::character::complete::char;
-use nom::combinator::{map_res, opt};
+use nom::combinator::opt;
 use nom::number::complete::float;
 
 use crate::parse::NmeaSentence;
-use crate::sentences::utils::{parse_hms, parse_lat_lon};
+use crate::sentences::utils::{array_string, parse_hms, parse_lat_lon, parse_str_field};
 use crate::NmeaError;
 
 const MAX_LEN: usize = 64;
@@ -22,7 +21,7 @@ pub struct BwcData {
     pub waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
 }
 
-fn do_parse_bwc(i: &[u8]) -> Result<BwcData, NmeaError> {
+fn do_parse_bwc(i: &[u8]) -> Result<BwcData, NmeaError<'_>> {
     /*
     BWC - Bearing & Distance to Waypoint - Great Circle
                                                             12
@@ -64,18 +63,11 @@ fn do_parse_bwc(i: &[u8]) -> Result<BwcData, NmeaError> {
     let (i, _) = char(',')(i)?;
 
     // 12. Waypoint ID
-    let (_i, waypoint_id) = opt(map_res(is_not(",*"), std::str::from_utf8))(i)?;
+    let (_i, waypoint_id) = parse_str_field(i)?;
 
     // 13. FAA mode indicator (NMEA 2.3 and later, optional)
 
-    let waypoint_id = if let Some(waypoint_id) = waypoint_id {
-        Some(
-            ArrayString::from(waypoint_id)
-                .map_err(|_e| NmeaError::SentenceLength(waypoint_id.len()))?,
-        )
-    } else {
-        None
-    };
+    let waypoint_id = array_string(waypoint_id)?;
 
     Ok(BwcData {
         fix_time,
@@ -101,9 +93,22 @@ pub fn parse_bwc(sentence: NmeaSentence) -> Result<BwcData, NmeaError> {
     }
 }
 
+/// Parse BWR message, same as BWC along the rhumb line
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_bwr_bearing_and_distance_to_waypoint_rhumb_line
+pub fn parse_bwr(sentence: NmeaSentence) -> Result<BwcData, NmeaError> {
+    if sentence.message_id != b"BWR" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"BWR",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_bwc(sentence.data)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use approx::relative_eq;
+    use approx::{assert_relative_eq, relative_eq};
 
     use crate::parse::parse_nmea_sentence;
 
@@ -120,7 +125,10 @@ mod tests {
 
         let data = parse_bwc(sentence).unwrap();
 
-        assert_eq!(data.fix_time.unwrap(), NaiveTime::from_hms(22, 5, 16));
+        assert_eq!(
+            data.fix_time.unwrap(),
+            NaiveTime::from_hms_opt(22, 5, 16).unwrap()
+        );
         relative_eq!(data.latitude.unwrap(), 51. + 30.02 / 60.);
         relative_eq!(data.longitude.unwrap(), 46.34 / 60.);
         relative_eq!(data.true_bearing.unwrap(), 213.8);
@@ -139,7 +147,7 @@ mod tests {
 
         assert_eq!(
             BwcData {
-                fix_time: Some(NaiveTime::from_hms(8, 18, 37)),
+                fix_time: Some(NaiveTime::from_hms_opt(8, 18, 37).unwrap()),
                 latitude: None,
                 longitude: None,
                 true_bearing: None,
@@ -150,4 +158,28 @@ mod tests {
             data
         );
     }
+
+    #[test]
+    fn test_parse_bwr() {
+        let sentence = parse_nmea_sentence(
+            b"$GPBWR,081837,3751.65,S,14507.36,E,015.0,T,002.4,M,0005.8,N,EGLM*37",
+        )
+        .unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_bwr(sentence).unwrap();
+
+        assert_relative_eq!(data.latitude.unwrap(), -(37. + 51.65 / 60.));
+        assert_relative_eq!(data.longitude.unwrap(), 145. + 7.36 / 60.);
+        assert_eq!(data.true_bearing, Some(15.));
+        assert_eq!(data.magnetic_bearing, Some(2.4));
+        assert_eq!(data.distance, Some(5.8));
+        assert_eq!(&data.waypoint_id.unwrap(), "EGLM");
+
+        let sentence = parse_nmea_sentence(b"$GPBWC,081837,,,,,,T,,M,,N,*13").unwrap();
+        assert!(matches!(
+            parse_bwr(sentence),
+            Err(NmeaError::WrongSentenceHeader { .. })
+        ));
+    }
 }
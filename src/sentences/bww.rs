@@ -0,0 +1,72 @@
+use arrayvec::ArrayString;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::bod::parse_bearings_and_waypoints;
+use crate::sentences::utils::array_string;
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct BwwData {
+    /// Bearing from the FROM waypoint to the TO waypoint, degrees True
+    pub true_bearing: Option<f32>,
+    /// Bearing from the FROM waypoint to the TO waypoint, degrees Magnetic
+    pub magnetic_bearing: Option<f32>,
+    pub to_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub from_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+fn do_parse_bww(i: &[u8]) -> Result<BwwData, NmeaError<'_>> {
+    let (_i, (true_bearing, magnetic_bearing, to_waypoint_id, from_waypoint_id)) =
+        parse_bearings_and_waypoints(i)?;
+    Ok(BwwData {
+        true_bearing,
+        magnetic_bearing,
+        to_waypoint_id: array_string(to_waypoint_id)?,
+        from_waypoint_id: array_string(from_waypoint_id)?,
+    })
+}
+
+/// Parse BWW message
+/// from gpsd:
+/// $--BWW,x.x,T,x.x,M,c--c,c--c*hh
+/// 1     x.x   Bearing, degrees True
+/// 2     T     T = True
+/// 3     x.x   Bearing, degrees Magnetic
+/// 4     M     M = Magnetic
+/// 5     c--c  TO Waypoint ID
+/// 6     c--c  FROM Waypoint ID
+pub fn parse_bww(sentence: NmeaSentence) -> Result<BwwData, NmeaError> {
+    if sentence.message_id != b"BWW" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"BWW",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_bww(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_bww() {
+        let sentence = parse_nmea_sentence(b"$GPBWW,213.8,T,218.0,M,TOWPT,FROMWPT*42").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            BwwData {
+                true_bearing: Some(213.8),
+                magnetic_bearing: Some(218.),
+                to_waypoint_id: Some(ArrayString::from("TOWPT").unwrap()),
+                from_waypoint_id: Some(ArrayString::from("FROMWPT").unwrap()),
+            },
+            parse_bww(sentence).unwrap()
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use nom::IResult;
+
+use crate::sentences::dbt::parse_depth;
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct DbkData {
+    /// Water depth below the keel, meters
+    pub depth: Option<f32>,
+}
+
+fn do_parse_dbk(i: &[u8]) -> IResult<&[u8], DbkData> {
+    let (i, depth) = parse_depth(i)?;
+    Ok((i, DbkData { depth }))
+}
+
+/// Parse DBK message
+/// from gpsd:
+/// $--DBK,x.x,f,x.x,M,x.x,F*hh
+/// 1     x.x   Water depth, feet
+/// 2     f     f = feet
+/// 3     x.x   Water depth, meters
+/// 4     M     M = meters
+/// 5     x.x   Water depth, fathoms
+/// 6     F     F = fathoms
+///
+/// The depth is relative to the keel.
+pub fn parse_dbk(sentence: NmeaSentence) -> Result<DbkData, NmeaError> {
+    if sentence.message_id != b"DBK" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"DBK",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_dbk(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_dbk() {
+        let s = parse_nmea_sentence(b"$SDDBK,22.1,f,6.7,M,3.7,F*2D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_relative_eq!(parse_dbk(s).unwrap().depth.unwrap(), 6.7);
+    }
+}
@@ -0,0 +1,51 @@
+use nom::IResult;
+
+use crate::sentences::dbt::parse_depth;
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct DbsData {
+    /// Water depth below the surface, meters
+    pub depth: Option<f32>,
+}
+
+fn do_parse_dbs(i: &[u8]) -> IResult<&[u8], DbsData> {
+    let (i, depth) = parse_depth(i)?;
+    Ok((i, DbsData { depth }))
+}
+
+/// Parse DBS message
+/// from gpsd:
+/// $--DBS,x.x,f,x.x,M,x.x,F*hh
+/// 1     x.x   Water depth, feet
+/// 2     f     f = feet
+/// 3     x.x   Water depth, meters
+/// 4     M     M = meters
+/// 5     x.x   Water depth, fathoms
+/// 6     F     F = fathoms
+///
+/// The depth is relative to the water surface.
+pub fn parse_dbs(sentence: NmeaSentence) -> Result<DbsData, NmeaError> {
+    if sentence.message_id != b"DBS" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"DBS",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_dbs(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_dbs() {
+        let s = parse_nmea_sentence(b"$SDDBS,,f,,M,5.5,F*01").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_relative_eq!(parse_dbs(s).unwrap().depth.unwrap(), 10.0584);
+    }
+}
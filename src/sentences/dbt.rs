@@ -0,0 +1,87 @@
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+const METERS_PER_FOOT: f32 = 0.3048;
+const METERS_PER_FATHOM: f32 = 1.8288;
+
+/// Parse the `x.x,f,x.x,M,x.x,F` depth fields shared by DBT, DBS and DBK,
+/// the depth is returned in meters from the first unit that is filled
+/// in, preferring meters.
+pub(crate) fn parse_depth(i: &[u8]) -> IResult<&[u8], Option<f32>> {
+    let (i, feet) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('f'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, meters) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, fathoms) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('F'))(i)?;
+    Ok((
+        i,
+        meters
+            .or_else(|| feet.map(|feet| feet * METERS_PER_FOOT))
+            .or_else(|| fathoms.map(|fathoms| fathoms * METERS_PER_FATHOM)),
+    ))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DbtData {
+    /// Water depth below the transducer, meters
+    pub depth: Option<f32>,
+}
+
+fn do_parse_dbt(i: &[u8]) -> IResult<&[u8], DbtData> {
+    let (i, depth) = parse_depth(i)?;
+    Ok((i, DbtData { depth }))
+}
+
+/// Parse DBT message
+/// from gpsd:
+/// $--DBT,x.x,f,x.x,M,x.x,F*hh
+/// 1     x.x   Water depth, feet
+/// 2     f     f = feet
+/// 3     x.x   Water depth, meters
+/// 4     M     M = meters
+/// 5     x.x   Water depth, fathoms
+/// 6     F     F = fathoms
+///
+/// The depth is relative to the transducer.
+pub fn parse_dbt(sentence: NmeaSentence) -> Result<DbtData, NmeaError> {
+    if sentence.message_id != b"DBT" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"DBT",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_dbt(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_dbt() {
+        let s = parse_nmea_sentence(b"$SDDBT,7.8,f,2.4,M,1.3,F*0D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_relative_eq!(parse_dbt(s).unwrap().depth.unwrap(), 2.4);
+
+        let s = parse_nmea_sentence(b"$SDDBT,7.8,f,,M,,F*09").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_relative_eq!(parse_dbt(s).unwrap().depth.unwrap(), 2.37744);
+
+        let s = parse_nmea_sentence(b"$SDDBT,,f,,M,,F*28").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(DbtData { depth: None }, parse_dbt(s).unwrap());
+    }
+}
@@ -0,0 +1,95 @@
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct DptData {
+    /// Water depth relative to the transducer, meters
+    pub depth: Option<f32>,
+    /// Offset from the transducer, meters. Positive is the distance from the
+    /// transducer to the water line, negative is the distance from the
+    /// transducer to the keel.
+    pub offset: Option<f32>,
+    /// Maximum range scale in use, meters
+    pub max_range: Option<f32>,
+}
+
+fn do_parse_dpt(i: &[u8]) -> IResult<&[u8], DptData> {
+    let (i, depth) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, offset) = opt(float)(i)?;
+    let (i, max_range) = opt(preceded(char(','), opt(float)))(i)?;
+    Ok((
+        i,
+        DptData {
+            depth,
+            offset,
+            max_range: max_range.flatten(),
+        },
+    ))
+}
+
+/// Parse DPT message
+/// from gpsd:
+/// $--DPT,x.x,x.x,x.x*hh
+/// 1     x.x   Water depth relative to transducer, meters
+/// 2     x.x   Offset from transducer, meters, positive means distance from
+///             transducer to water line, negative means distance from
+///             transducer to keel
+/// 3     x.x   Maximum range scale in use (NMEA 3.0 and later)
+pub fn parse_dpt(sentence: NmeaSentence) -> Result<DptData, NmeaError> {
+    if sentence.message_id != b"DPT" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"DPT",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_dpt(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_dpt() {
+        let s = parse_nmea_sentence(b"$SDDPT,2.4,0.5,100*49").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            DptData {
+                depth: Some(2.4),
+                offset: Some(0.5),
+                max_range: Some(100.),
+            },
+            parse_dpt(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$SDDPT,2.4,-1.2*7F").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            DptData {
+                depth: Some(2.4),
+                offset: Some(-1.2),
+                max_range: None,
+            },
+            parse_dpt(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$SDDPT,,,*7B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            DptData {
+                depth: None,
+                offset: None,
+                max_range: None,
+            },
+            parse_dpt(s).unwrap()
+        );
+    }
+}
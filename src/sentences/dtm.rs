@@ -0,0 +1,196 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 16;
+
+const WGS84_DATUM: &str = "W84";
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DtmData {
+    /// Local datum code, W84, W72, S85, P90, 999 for a user defined datum or
+    /// an IHO datum code
+    pub local_datum: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub subdivision: Option<ArrayString<[u8; MAX_LEN]>>,
+    /// Latitude offset of the local datum from the reference datum, degrees,
+    /// negative to the south
+    pub latitude_offset: Option<f64>,
+    /// Longitude offset of the local datum from the reference datum, degrees,
+    /// negative to the west
+    pub longitude_offset: Option<f64>,
+    /// Altitude offset of the local datum from the reference datum, meters
+    pub altitude_offset: Option<f32>,
+    pub reference_datum: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+// Option::is_none_or would raise the minimum Rust version to 1.82
+#[allow(clippy::unnecessary_map_or)]
+impl DtmData {
+    /// True when the positions are reported in the WGS84 datum
+    pub fn is_wgs84(&self) -> bool {
+        self.local_datum.map_or(true, |datum| &datum == WGS84_DATUM)
+    }
+
+    /// Latitude, longitude and altitude offsets of the local datum from
+    /// WGS84, missing offsets count as zero.
+    /// None if the offsets are relative to another reference datum.
+    pub fn wgs84_offsets(&self) -> Option<(f64, f64, f32)> {
+        if self.is_wgs84() {
+            Some((0., 0., 0.))
+        } else if self
+            .reference_datum
+            .map_or(true, |datum| &datum == WGS84_DATUM)
+        {
+            Some((
+                self.latitude_offset.unwrap_or(0.),
+                self.longitude_offset.unwrap_or(0.),
+                self.altitude_offset.unwrap_or(0.),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+fn parse_offset<'a>(i: &'a [u8], hemispheres: &'static str) -> IResult<&'a [u8], Option<f64>> {
+    let (i, minutes) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, hemisphere) = opt(one_of(hemispheres))(i)?;
+    Ok((
+        i,
+        minutes.map(|minutes| {
+            let degrees = f64::from(minutes) / 60.;
+            if hemisphere == hemispheres.chars().nth(1) {
+                -degrees
+            } else {
+                degrees
+            }
+        }),
+    ))
+}
+
+fn do_parse_dtm(i: &[u8]) -> Result<DtmData, NmeaError<'_>> {
+    // 1. Local datum code
+    let (i, local_datum) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Local datum subdivision code
+    let (i, subdivision) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Latitude offset, minutes
+    // 4. N or S
+    let (i, latitude_offset) = parse_offset(i, "NS")?;
+    let (i, _) = char(',')(i)?;
+    // 5. Longitude offset, minutes
+    // 6. E or W
+    let (i, longitude_offset) = parse_offset(i, "EW")?;
+    let (i, _) = char(',')(i)?;
+    // 7. Altitude offset, meters
+    let (i, altitude_offset) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Reference datum code
+    let (_i, reference_datum) = parse_str_field(i)?;
+
+    Ok(DtmData {
+        local_datum: array_string(local_datum)?,
+        subdivision: array_string(subdivision)?,
+        latitude_offset,
+        longitude_offset,
+        altitude_offset,
+        reference_datum: array_string(reference_datum)?,
+    })
+}
+
+/// Parse DTM message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_dtm_datum_reference
+///
+/// $--DTM,ccc,a,x.x,a,x.x,a,x.x,ccc*hh<CR><LF>
+///
+/// Offsets are normalised to signed degrees, the position in the local datum
+/// is the position in the reference datum plus the offsets.
+pub fn parse_dtm(sentence: NmeaSentence) -> Result<DtmData, NmeaError> {
+    if sentence.message_id != b"DTM" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"DTM",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_dtm(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_dtm() {
+        let sentence = parse_nmea_sentence(b"$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_dtm(sentence).unwrap();
+
+        assert!(data.is_wgs84());
+        assert_eq!(data.subdivision, None);
+        assert_eq!(data.latitude_offset, Some(0.));
+        assert_eq!(&data.reference_datum.unwrap(), "W84");
+
+        let sentence = parse_nmea_sentence(b"$GPDTM,S85,,0.12,S,0.30,W,12.5,W84*53").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_dtm(sentence).unwrap();
+
+        assert!(!data.is_wgs84());
+        assert_eq!(&data.local_datum.unwrap(), "S85");
+        assert_relative_eq!(data.latitude_offset.unwrap(), -0.002, epsilon = 1e-9);
+        assert_relative_eq!(data.longitude_offset.unwrap(), -0.005, epsilon = 1e-9);
+        assert_eq!(data.altitude_offset, Some(12.5));
+        assert_eq!(
+            data.wgs84_offsets(),
+            Some((
+                data.latitude_offset.unwrap(),
+                data.longitude_offset.unwrap(),
+                12.5
+            ))
+        );
+
+        let sentence = parse_nmea_sentence(b"$GPDTM,999,A,1.5,N,2.5,E,,P90*63").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_dtm(sentence).unwrap();
+
+        assert_eq!(&data.subdivision.unwrap(), "A");
+        assert_relative_eq!(data.latitude_offset.unwrap(), 0.025, epsilon = 1e-9);
+        assert_relative_eq!(data.longitude_offset.unwrap(), 2.5 / 60., epsilon = 1e-9);
+        assert_eq!(data.altitude_offset, None);
+        assert_eq!(&data.reference_datum.unwrap(), "P90");
+        assert_eq!(data.wgs84_offsets(), None);
+    }
+
+    #[test]
+    fn test_parse_dtm_empty() {
+        let sentence = parse_nmea_sentence(b"$GPDTM,W84,,,,,,,*11").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            DtmData {
+                local_datum: Some(ArrayString::from("W84").unwrap()),
+                subdivision: None,
+                latitude_offset: None,
+                longitude_offset: None,
+                altitude_offset: None,
+                reference_datum: None,
+            },
+            parse_dtm(sentence).unwrap()
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::NaiveTime;
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{hex_number, number, parse_hms};
+use crate::NmeaError;
+
+/// RAIM integrity status of a fix, as reported by GBS
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IntegrityStatus {
+    /// No satellite was flagged as faulty
+    Ok,
+    /// The satellite with this ID was flagged as most likely failed
+    FaultySatellite(u32),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GbsData {
+    pub fix_time: Option<NaiveTime>,
+    pub lat_error: Option<f32>,
+    pub lon_error: Option<f32>,
+    pub alt_error: Option<f32>,
+    pub faulty_sat_id: Option<u32>,
+    pub prob_of_missed_detection: Option<f32>,
+    pub bias_estimate: Option<f32>,
+    pub bias_sd: Option<f32>,
+    pub system_id: Option<u8>,
+    pub signal_id: Option<u8>,
+}
+
+impl GbsData {
+    /// Returns whether a satellite was flagged as faulty
+    pub fn integrity_status(&self) -> IntegrityStatus {
+        match self.faulty_sat_id {
+            Some(id) => IntegrityStatus::FaultySatellite(id),
+            None => IntegrityStatus::Ok,
+        }
+    }
+}
+
+fn do_parse_gbs(i: &[u8]) -> IResult<&[u8], GbsData> {
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lat_error) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lon_error) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, alt_error) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, faulty_sat_id) = opt(number::<u32>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, prob_of_missed_detection) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, bias_estimate) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, bias_sd) = opt(float)(i)?;
+    let (i, system_id) = opt(preceded(char(','), opt(hex_number)))(i)?;
+    let (i, signal_id) = opt(preceded(char(','), opt(hex_number)))(i)?;
+    Ok((
+        i,
+        GbsData {
+            fix_time,
+            lat_error,
+            lon_error,
+            alt_error,
+            faulty_sat_id,
+            prob_of_missed_detection,
+            bias_estimate,
+            bias_sd,
+            system_id: system_id.flatten(),
+            signal_id: signal_id.flatten(),
+        },
+    ))
+}
+
+/// Parse GBS message
+/// from NMEA 4.1:
+/// $--GBS,hhmmss.ss,x.x,x.x,x.x,x.x,x.x,x.x,x.x,h,h*hh
+/// 1     hhmmss.ss  UTC time of the GGA or GNS fix associated with this sentence
+/// 2     x.x        Expected error in latitude (meters)
+/// 3     x.x        Expected error in longitude (meters)
+/// 4     x.x        Expected error in altitude (meters)
+/// 5     x.x        ID number of most likely failed satellite
+/// 6     x.x        Probability of missed detection for most likely failed satellite
+/// 7     x.x        Estimate of bias in meters on most likely failed satellite
+/// 8     x.x        Standard deviation of bias estimate
+/// 9     h          GNSS System ID (NMEA 4.1 and later)
+/// 10    h          GNSS Signal ID (NMEA 4.1 and later)
+pub fn parse_gbs(sentence: NmeaSentence) -> Result<GbsData, NmeaError> {
+    if sentence.message_id != b"GBS" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"GBS",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_gbs(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_gbs() {
+        let s =
+            parse_nmea_sentence(b"$GPGBS,015509.00,-0.031,-0.186,0.219,19,0.000,-0.354,6.972*4D")
+                .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gbs = parse_gbs(s).unwrap();
+        assert_eq!(
+            GbsData {
+                fix_time: Some(NaiveTime::from_hms_opt(1, 55, 9).unwrap()),
+                lat_error: Some(-0.031),
+                lon_error: Some(-0.186),
+                alt_error: Some(0.219),
+                faulty_sat_id: Some(19),
+                prob_of_missed_detection: Some(0.),
+                bias_estimate: Some(-0.354),
+                bias_sd: Some(6.972),
+                system_id: None,
+                signal_id: None,
+            },
+            gbs
+        );
+        assert_eq!(gbs.integrity_status(), IntegrityStatus::FaultySatellite(19));
+
+        let s = parse_nmea_sentence(b"$GNGBS,181604.00,,,,,,,*7B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gbs = parse_gbs(s).unwrap();
+        assert_eq!(gbs.lat_error, None);
+        assert_eq!(gbs.integrity_status(), IntegrityStatus::Ok);
+
+        let s = parse_nmea_sentence(b"$GNGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0*44").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gbs = parse_gbs(s).unwrap();
+        assert_eq!(gbs.faulty_sat_id, Some(3));
+        assert_eq!(gbs.prob_of_missed_detection, None);
+        assert_eq!(gbs.system_id, Some(1));
+        assert_eq!(gbs.signal_id, Some(0));
+    }
+}
@@ -96,12 +96,17 @@ mod tests {
     fn test_parse_gga_full() {
         let data = parse_gga(NmeaSentence {
             talker_id: b"GP",
+            manufacturer: None,
             message_id: b"GGA",
+            has_data: true,
             data: b"033745.0,5650.82344,N,03548.9778,E,1,07,1.8,101.2,M,14.7,M,,",
             checksum: 0x57,
         })
         .unwrap();
-        assert_eq!(data.fix_time.unwrap(), NaiveTime::from_hms(3, 37, 45));
+        assert_eq!(
+            data.fix_time.unwrap(),
+            NaiveTime::from_hms_opt(3, 37, 45).unwrap()
+        );
         assert_eq!(data.fix_type.unwrap(), FixType::Gps);
         relative_eq!(data.latitude.unwrap(), 56. + 50.82344 / 60.);
         relative_eq!(data.longitude.unwrap(), 35. + 48.9778 / 60.);
@@ -0,0 +1,260 @@
+use arrayvec::ArrayVec;
+use chrono::NaiveTime;
+use nom::bytes::complete::{take_until, take_while};
+use nom::character::complete::{char, one_of};
+use nom::combinator::{map_res, opt};
+use nom::number::complete::float;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{number, parse_float_num, parse_hms, parse_lat_lon};
+use crate::{FixType, GnssType, NmeaError};
+
+/// Constellations in the order their mode indicators appear in a GNS sentence
+const GNS_MODE_ORDER: [GnssType; 6] = [
+    GnssType::Gps,
+    GnssType::Glonass,
+    GnssType::Galileo,
+    GnssType::Beidou,
+    GnssType::Qzss,
+    GnssType::NavIC,
+];
+
+type GnsModes = ArrayVec<[(GnssType, GnsMode); 6]>;
+
+/// Mode indicator of one constellation in a GNS sentence
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GnsMode {
+    NoFix,
+    Autonomous,
+    Differential,
+    Precise,
+    RtkFixed,
+    RtkFloat,
+    Estimated,
+    Manual,
+    Simulator,
+}
+
+impl GnsMode {
+    pub(crate) fn from_char(c: u8) -> Option<Self> {
+        match c {
+            b'N' => Some(GnsMode::NoFix),
+            b'A' => Some(GnsMode::Autonomous),
+            b'D' => Some(GnsMode::Differential),
+            b'P' => Some(GnsMode::Precise),
+            b'R' => Some(GnsMode::RtkFixed),
+            b'F' => Some(GnsMode::RtkFloat),
+            b'E' => Some(GnsMode::Estimated),
+            b'M' => Some(GnsMode::Manual),
+            b'S' => Some(GnsMode::Simulator),
+            _ => None,
+        }
+    }
+}
+
+impl From<GnsMode> for FixType {
+    fn from(mode: GnsMode) -> Self {
+        match mode {
+            GnsMode::NoFix => FixType::Invalid,
+            GnsMode::Autonomous => FixType::Gps,
+            GnsMode::Differential => FixType::DGps,
+            GnsMode::Precise => FixType::Pps,
+            GnsMode::RtkFixed => FixType::Rtk,
+            GnsMode::RtkFloat => FixType::FloatRtk,
+            GnsMode::Estimated => FixType::Estimated,
+            GnsMode::Manual => FixType::Manual,
+            GnsMode::Simulator => FixType::Simulation,
+        }
+    }
+}
+
+/// Navigational status indicator (NMEA 4.1 and later)
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GnsNavStatus {
+    Safe,
+    Caution,
+    Unsafe,
+    NotValid,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GnsData {
+    pub fix_time: Option<NaiveTime>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    pub modes: GnsModes,
+    pub fix_satellites: Option<u32>,
+    pub hdop: Option<f32>,
+    pub altitude: Option<f32>,
+    pub geoid_height: Option<f32>,
+    pub diff_age: Option<f32>,
+    pub diff_station: Option<u16>,
+    pub nav_status: Option<GnsNavStatus>,
+}
+
+impl GnsData {
+    /// Returns the mode indicator of the given constellation, if it was sent
+    pub fn mode(&self, gnss_type: GnssType) -> Option<GnsMode> {
+        self.modes
+            .iter()
+            .find(|(t, _)| *t == gnss_type)
+            .map(|(_, mode)| *mode)
+    }
+
+    /// Fix type of the first constellation which has a fix,
+    /// `FixType::Invalid` if none of them has
+    pub fn fix_type(&self) -> FixType {
+        self.modes
+            .iter()
+            .map(|(_, mode)| FixType::from(*mode))
+            .find(|fix_type| *fix_type != FixType::Invalid)
+            .unwrap_or(FixType::Invalid)
+    }
+}
+
+fn parse_modes(i: &[u8]) -> IResult<&[u8], GnsModes> {
+    map_res(
+        take_while(|c: u8| c.is_ascii_uppercase()),
+        |modes: &[u8]| -> Result<_, &'static str> {
+            if modes.len() > GNS_MODE_ORDER.len() {
+                return Err("Too many GNS mode indicators");
+            }
+            modes
+                .iter()
+                .zip(GNS_MODE_ORDER.iter())
+                .map(|(c, gnss_type)| {
+                    GnsMode::from_char(*c)
+                        .map(|mode| (*gnss_type, mode))
+                        .ok_or("Invalid GNS mode indicator")
+                })
+                .collect()
+        },
+    )(i)
+}
+
+fn do_parse_gns(i: &[u8]) -> IResult<&[u8], GnsData> {
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, modes) = parse_modes(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, fix_satellites) = opt(number::<u32>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, hdop) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, altitude) = opt(map_res(take_until(","), parse_float_num::<f32>))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, geoid_height) = opt(map_res(take_until(","), parse_float_num::<f32>))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, diff_age) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, diff_station) = opt(number::<u16>)(i)?;
+    let (i, nav_status) = opt(preceded(char(','), opt(one_of("SCUV"))))(i)?;
+    Ok((
+        i,
+        GnsData {
+            fix_time,
+            lat: lat_lon.map(|v| v.0),
+            lon: lat_lon.map(|v| v.1),
+            modes,
+            fix_satellites,
+            hdop,
+            altitude,
+            geoid_height,
+            diff_age,
+            diff_station,
+            nav_status: nav_status.flatten().map(|c| match c {
+                'S' => GnsNavStatus::Safe,
+                'C' => GnsNavStatus::Caution,
+                'U' => GnsNavStatus::Unsafe,
+                'V' => GnsNavStatus::NotValid,
+                _ => unreachable!(),
+            }),
+        },
+    ))
+}
+
+/// Parse GNS message
+/// from NMEA 4.1:
+/// $--GNS,hhmmss.ss,llll.ll,a,yyyyy.yy,a,c--c,xx,x.x,x.x,x.x,x.x,x.x,a*hh
+/// 1     hhmmss.ss  UTC of position
+/// 2,3   llll.ll,a  Latitude, N/S
+/// 4,5   yyyyy.yy,a Longitude, E/W
+/// 6     c--c       Mode indicator, one character per constellation in the order
+///                  GPS, GLONASS, Galileo, BeiDou, QZSS, NavIC:
+///                  N = No fix, A = Autonomous, D = Differential, P = Precise,
+///                  R = RTK fixed, F = RTK float, E = Estimated (dead reckoning),
+///                  M = Manual input, S = Simulator
+/// 7     xx         Total number of satellites in use, 00-99
+/// 8     x.x        HDOP
+/// 9     x.x        Antenna altitude, meters, re: mean-sea-level (geoid)
+/// 10    x.x        Geoidal separation, meters
+/// 11    x.x        Age of differential data
+/// 12    x.x        Differential reference station ID
+/// 13    a          Navigational status indicator (NMEA 4.1 and later):
+///                  S = Safe, C = Caution, U = Unsafe, V = Not valid
+pub fn parse_gns(sentence: NmeaSentence) -> Result<GnsData, NmeaError> {
+    if sentence.message_id != b"GNS" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"GNS",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_gns(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_gns() {
+        let s = parse_nmea_sentence(
+            b"$GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,*70",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gns = parse_gns(s).unwrap();
+        assert_eq!(
+            gns.fix_time,
+            Some(NaiveTime::from_hms_opt(1, 40, 35).unwrap())
+        );
+        assert_relative_eq!(gns.lat.unwrap(), -(43. + 32.69262 / 60.));
+        assert_relative_eq!(gns.lon.unwrap(), 172. + 35.48549 / 60.);
+        assert_eq!(gns.modes.len(), 2);
+        assert_eq!(gns.mode(GnssType::Gps), Some(GnsMode::RtkFixed));
+        assert_eq!(gns.mode(GnssType::Glonass), Some(GnsMode::RtkFixed));
+        assert_eq!(gns.mode(GnssType::Galileo), None);
+        assert_eq!(gns.fix_type(), FixType::Rtk);
+        assert_eq!(gns.fix_satellites, Some(13));
+        assert_relative_eq!(gns.hdop.unwrap(), 0.9);
+        assert_relative_eq!(gns.altitude.unwrap(), 25.63);
+        assert_relative_eq!(gns.geoid_height.unwrap(), 11.24);
+        assert_eq!(gns.diff_age, None);
+        assert_eq!(gns.diff_station, None);
+        assert_eq!(gns.nav_status, None);
+
+        let s = parse_nmea_sentence(b"$GNGNS,181604.00,,,,,NN,00,99.99,,,,*59").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gns = parse_gns(s).unwrap();
+        assert_eq!(gns.lat, None);
+        assert_eq!(gns.fix_type(), FixType::Invalid);
+        assert_eq!(gns.fix_satellites, Some(0));
+
+        let s = parse_nmea_sentence(
+            b"$GNGNS,112257.00,3844.24011,N,00908.43828,W,AANN,10,1.0,129.0,50.0,,,V*34",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gns = parse_gns(s).unwrap();
+        assert_eq!(gns.mode(GnssType::Beidou), Some(GnsMode::NoFix));
+        assert_eq!(gns.fix_type(), FixType::Gps);
+        assert_eq!(gns.nav_status, Some(GnsNavStatus::NotValid));
+    }
+}
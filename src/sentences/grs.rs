@@ -0,0 +1,153 @@
+use chrono::NaiveTime;
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::multi::many_m_n;
+use nom::number::complete::float;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{hex_number, parse_hms};
+use crate::{GnssType, NmeaError};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GrsMode {
+    /// Residuals were used to calculate the position given in the matching
+    /// GGA or GNS sentence
+    UsedInFix,
+    /// Residuals were recomputed after the position was computed
+    RecomputedAfterFix,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GrsData {
+    pub fix_time: Option<NaiveTime>,
+    pub mode: GrsMode,
+    /// Range residuals in meters, in the order of the satellite PRNs of the
+    /// GSA sentence
+    pub residuals: [Option<f32>; 12],
+    /// NMEA 4.1 GNSS system ID
+    pub system_id: Option<u8>,
+    /// NMEA 4.1 GNSS signal ID
+    pub signal_id: Option<u8>,
+}
+
+impl GrsData {
+    /// Constellation of the residuals, None before NMEA 4.1
+    pub fn gnss_type(&self) -> Option<GnssType> {
+        match self.system_id? {
+            1 => Some(GnssType::Gps),
+            2 => Some(GnssType::Glonass),
+            3 => Some(GnssType::Galileo),
+            4 => Some(GnssType::Beidou),
+            5 => Some(GnssType::Qzss),
+            6 => Some(GnssType::NavIC),
+            _ => None,
+        }
+    }
+}
+
+fn do_parse_grs(i: &[u8]) -> IResult<&[u8], GrsData> {
+    // 1. UTC time of the associated GGA or GNS fix
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Mode, 0 = used in the fix, 1 = recomputed
+    let (i, mode) = one_of("01")(i)?;
+    // 3. - 14. Range residuals, meters
+    let (i, fields) = many_m_n(1, 12, preceded(char(','), opt(float)))(i)?;
+    // 15. GNSS system ID (NMEA 4.1)
+    let (i, system_id) = opt(preceded(char(','), opt(hex_number)))(i)?;
+    // 16. GNSS signal ID (NMEA 4.1)
+    let (i, signal_id) = opt(preceded(char(','), opt(hex_number)))(i)?;
+
+    let mut residuals = [None; 12];
+    residuals[..fields.len()].copy_from_slice(&fields);
+    Ok((
+        i,
+        GrsData {
+            fix_time,
+            mode: match mode {
+                '0' => GrsMode::UsedInFix,
+                '1' => GrsMode::RecomputedAfterFix,
+                _ => unreachable!(),
+            },
+            residuals,
+            system_id: system_id.flatten(),
+            signal_id: signal_id.flatten(),
+        },
+    ))
+}
+
+/// Parse GRS message
+/// from gpsd:
+/// $--GRS,hhmmss.ss,m,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,h,h*hh
+/// 1     hhmmss.ss  UTC time of the associated GGA or GNS fix
+/// 2     m          Mode, 0 = residuals were used to calculate the position
+///                  given in the matching GGA or GNS sentence, 1 = residuals
+///                  were recomputed after the position was computed
+/// 3-14  x.x        Range residuals in meters for satellites used in the
+///                  navigation solution, in the order of the matching GSA
+/// 15    h          NMEA 4.1 GNSS system ID
+/// 16    h          NMEA 4.1 GNSS signal ID
+pub fn parse_grs(sentence: NmeaSentence) -> Result<GrsData, NmeaError> {
+    if sentence.message_id != b"GRS" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"GRS",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_grs(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_grs() {
+        let s =
+            parse_nmea_sentence(b"$GPGRS,220320.0,0,-0.8,-0.2,-0.1,-0.2,0.8,0.6,,,,,,*79").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let grs = parse_grs(s).unwrap();
+        assert_eq!(
+            GrsData {
+                fix_time: NaiveTime::from_hms_opt(22, 3, 20),
+                mode: GrsMode::UsedInFix,
+                residuals: [
+                    Some(-0.8),
+                    Some(-0.2),
+                    Some(-0.1),
+                    Some(-0.2),
+                    Some(0.8),
+                    Some(0.6),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                ],
+                system_id: None,
+                signal_id: None,
+            },
+            grs
+        );
+        assert_eq!(grs.gnss_type(), None);
+    }
+
+    #[test]
+    fn test_parse_grs_nmea_4_1() {
+        let s = parse_nmea_sentence(b"$GNGRS,104148.00,1,,0.0,2.5,0.0,-2.8,,,,,,,,2,1*7B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let grs = parse_grs(s).unwrap();
+        assert_eq!(grs.mode, GrsMode::RecomputedAfterFix);
+        assert_eq!(grs.residuals[0], None);
+        assert_eq!(grs.residuals[2], Some(2.5));
+        assert_eq!(grs.residuals[4], Some(-2.8));
+        assert_eq!(grs.system_id, Some(2));
+        assert_eq!(grs.signal_id, Some(1));
+        assert_eq!(grs.gnss_type(), Some(GnssType::Glonass));
+    }
+}
@@ -4,11 +4,12 @@ use nom::character::complete::{char, one_of};
 use nom::combinator::{all_consuming, opt, value};
 use nom::multi::many0;
 use nom::number::complete::float;
-use nom::sequence::terminated;
+use nom::sequence::{preceded, terminated};
 use nom::IResult;
 
 use crate::parse::NmeaSentence;
-use crate::{sentences::utils::number, NmeaError};
+use crate::sentences::utils::{hex_number, number};
+use crate::NmeaError;
 
 #[derive(PartialEq, Debug)]
 pub enum GsaMode1 {
@@ -31,13 +32,21 @@ pub struct GsaData {
     pub pdop: Option<f32>,
     pub hdop: Option<f32>,
     pub vdop: Option<f32>,
+    /// GNSS system ID (NMEA 4.1), the PRNs are for this system only
+    pub system_id: Option<u8>,
 }
 
 fn gsa_prn_fields_parse(i: &[u8]) -> IResult<&[u8], Vec<Option<u32>>> {
     many0(terminated(opt(number::<u32>), char(',')))(i)
 }
 
-type GsaTail = (Vec<Option<u32>>, Option<f32>, Option<f32>, Option<f32>);
+type GsaTail = (
+    Vec<Option<u32>>,
+    Option<f32>,
+    Option<f32>,
+    Option<f32>,
+    Option<u8>,
+);
 
 fn do_parse_gsa_tail(i: &[u8]) -> IResult<&[u8], GsaTail> {
     let (i, prns) = gsa_prn_fields_parse(i)?;
@@ -46,7 +55,17 @@ fn do_parse_gsa_tail(i: &[u8]) -> IResult<&[u8], GsaTail> {
     let (i, hdop) = float(i)?;
     let (i, _) = char(',')(i)?;
     let (i, vdop) = float(i)?;
-    Ok((i, (prns, Some(pdop), Some(hdop), Some(vdop))))
+    let (i, system_id) = opt(preceded(char(','), opt(hex_number)))(i)?;
+    Ok((
+        i,
+        (
+            prns,
+            Some(pdop),
+            Some(hdop),
+            Some(vdop),
+            system_id.flatten(),
+        ),
+    ))
 }
 
 fn is_comma(x: u8) -> bool {
@@ -55,7 +74,7 @@ fn is_comma(x: u8) -> bool {
 
 fn do_parse_empty_gsa_tail(i: &[u8]) -> IResult<&[u8], GsaTail> {
     value(
-        (Vec::new(), None, None, None),
+        (Vec::new(), None, None, None, None),
         all_consuming(take_while1(is_comma)),
     )(i)
 }
@@ -84,6 +103,7 @@ fn do_parse_gsa(i: &[u8]) -> IResult<&[u8], GsaData> {
             pdop: tail.1,
             hdop: tail.2,
             vdop: tail.3,
+            system_id: tail.4,
         },
     ))
 }
@@ -100,6 +120,7 @@ fn do_parse_gsa(i: &[u8]) -> IResult<&[u8], GsaData> {
 /// 15   = PDOP
 /// 16   = HDOP
 /// 17   = VDOP
+/// 18   = GNSS system ID (NMEA 4.1)
 ///
 /// Not all documentation specifies the number of PRN fields, it
 /// may be variable.  Most doc that specifies says 12 PRNs.
@@ -168,9 +189,19 @@ mod tests {
                 pdop: Some(3.6),
                 hdop: Some(2.1),
                 vdop: Some(2.2),
+                system_id: None,
             },
             gsa
         );
+
+        let s =
+            parse_nmea_sentence(b"$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,2*09").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gsa = parse_gsa(s).unwrap();
+        assert_eq!(gsa.fix_sats_prn, [80, 71, 73, 79, 69]);
+        assert_eq!(gsa.vdop, Some(1.47));
+        assert_eq!(gsa.system_id, Some(2));
+
         let gsa_examples = [
             "$GPGSA,A,3,19,28,14,18,27,22,31,39,,,,,1.7,1.0,1.3*35",
             "$GPGSA,A,3,23,31,22,16,03,07,,,,,,,1.8,1.1,1.4*3E",
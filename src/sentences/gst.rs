@@ -0,0 +1,110 @@
+use chrono::NaiveTime;
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::parse_hms;
+use crate::NmeaError;
+
+#[derive(Debug, PartialEq)]
+pub struct GstData {
+    pub fix_time: Option<NaiveTime>,
+    pub rms_sd: Option<f32>,
+    pub ellipse_semi_major_sd: Option<f32>,
+    pub ellipse_semi_minor_sd: Option<f32>,
+    pub err_ellipse_orientation: Option<f32>,
+    pub lat_sd: Option<f32>,
+    pub lon_sd: Option<f32>,
+    pub alt_sd: Option<f32>,
+}
+
+fn do_parse_gst(i: &[u8]) -> IResult<&[u8], GstData> {
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, rms_sd) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, ellipse_semi_major_sd) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, ellipse_semi_minor_sd) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, err_ellipse_orientation) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lat_sd) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lon_sd) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, alt_sd) = opt(float)(i)?;
+    Ok((
+        i,
+        GstData {
+            fix_time,
+            rms_sd,
+            ellipse_semi_major_sd,
+            ellipse_semi_minor_sd,
+            err_ellipse_orientation,
+            lat_sd,
+            lon_sd,
+            alt_sd,
+        },
+    ))
+}
+
+/// Parse GST message
+/// from gpsd:
+/// $GPGST,182141.000,15.5,15.3,7.2,21.8,0.9,0.5,0.8*54
+/// 1     182141.000  UTC time of the associated GGA fix
+/// 2     15.5        Total RMS standard deviation of ranges inputs to the navigation solution
+/// 3     15.3        Standard deviation (meters) of semi-major axis of error ellipse
+/// 4     7.2         Standard deviation (meters) of semi-minor axis of error ellipse
+/// 5     21.8        Orientation of semi-major axis of error ellipse (true north degrees)
+/// 6     0.9         Standard deviation (meters) of latitude error
+/// 7     0.5         Standard deviation (meters) of longitude error
+/// 8     0.8         Standard deviation (meters) of altitude error
+pub fn parse_gst(sentence: NmeaSentence) -> Result<GstData, NmeaError> {
+    if sentence.message_id != b"GST" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"GST",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_gst(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_gst() {
+        let s =
+            parse_nmea_sentence(b"$GPGST,182141.000,15.5,15.3,7.2,21.8,0.9,0.5,0.8*54").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gst = parse_gst(s).unwrap();
+        assert_eq!(
+            GstData {
+                fix_time: Some(NaiveTime::from_hms_opt(18, 21, 41).unwrap()),
+                rms_sd: Some(15.5),
+                ellipse_semi_major_sd: Some(15.3),
+                ellipse_semi_minor_sd: Some(7.2),
+                err_ellipse_orientation: Some(21.8),
+                lat_sd: Some(0.9),
+                lon_sd: Some(0.5),
+                alt_sd: Some(0.8),
+            },
+            gst
+        );
+
+        let s =
+            parse_nmea_sentence(b"$GNGST,181604.00,0.0000,,,,5773795,5773795,5773794*4F").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gst = parse_gst(s).unwrap();
+        assert_eq!(gst.rms_sd, Some(0.));
+        assert_eq!(gst.ellipse_semi_major_sd, None);
+        assert_eq!(gst.err_ellipse_orientation, None);
+        assert_eq!(gst.alt_sd, Some(5773794.));
+    }
+}
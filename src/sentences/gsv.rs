@@ -32,6 +32,7 @@ fn parse_gsv_sat_info(i: &[u8]) -> IResult<&[u8], Satellite> {
             elevation: elevation.map(|v| v as f32),
             azimuth: azimuth.map(|v| v as f32),
             snr: snr.map(|v| v as f32),
+            residual: None,
         },
     ))
 }
@@ -122,7 +123,9 @@ mod tests {
     fn test_parse_gsv_full() {
         let data = parse_gsv(NmeaSentence {
             talker_id: b"GP",
+            manufacturer: None,
             message_id: b"GSV",
+            has_data: true,
             data: b"2,1,08,01,,083,46,02,17,308,,12,07,344,39,14,22,228,",
             checksum: 0,
         })
@@ -134,47 +137,53 @@ mod tests {
         assert_eq!(
             data.sats_info[0].clone().unwrap(),
             Satellite {
-                gnss_type: data.gnss_type.clone(),
+                gnss_type: data.gnss_type,
                 prn: 1,
                 elevation: None,
                 azimuth: Some(83.),
                 snr: Some(46.),
+                residual: None,
             }
         );
         assert_eq!(
             data.sats_info[1].clone().unwrap(),
             Satellite {
-                gnss_type: data.gnss_type.clone(),
+                gnss_type: data.gnss_type,
                 prn: 2,
                 elevation: Some(17.),
                 azimuth: Some(308.),
                 snr: None,
+                residual: None,
             }
         );
         assert_eq!(
             data.sats_info[2].clone().unwrap(),
             Satellite {
-                gnss_type: data.gnss_type.clone(),
+                gnss_type: data.gnss_type,
                 prn: 12,
                 elevation: Some(7.),
                 azimuth: Some(344.),
                 snr: Some(39.),
+                residual: None,
             }
         );
         assert_eq!(
             data.sats_info[3].clone().unwrap(),
             Satellite {
-                gnss_type: data.gnss_type.clone(),
+                gnss_type: data.gnss_type,
                 prn: 14,
                 elevation: Some(22.),
                 azimuth: Some(228.),
                 snr: None,
+                residual: None,
             }
         );
 
         let data = parse_gsv(NmeaSentence {
             talker_id: b"GL",
+            manufacturer: None,
             message_id: b"GSV",
+            has_data: true,
             data: b"3,3,10,72,40,075,43,87,00,000,",
             checksum: 0,
         })
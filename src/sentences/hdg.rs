@@ -0,0 +1,122 @@
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct HdgData {
+    /// Magnetic sensor heading, degrees
+    pub heading: Option<f32>,
+    /// Magnetic deviation, degrees, East is positive
+    pub deviation: Option<f32>,
+    /// Magnetic variation, degrees, East is positive
+    pub variation: Option<f32>,
+}
+
+impl HdgData {
+    /// Magnetic heading, the sensor heading corrected by the deviation
+    pub fn magnetic_heading(&self) -> Option<f32> {
+        self.heading
+            .map(|heading| (heading + self.deviation.unwrap_or(0.)).rem_euclid(360.))
+    }
+
+    /// True heading, the magnetic heading corrected by the variation.
+    /// None if the variation is not known.
+    pub fn true_heading(&self) -> Option<f32> {
+        match (self.magnetic_heading(), self.variation) {
+            (Some(heading), Some(variation)) => Some((heading + variation).rem_euclid(360.)),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn parse_east_west(i: &[u8]) -> IResult<&[u8], Option<f32>> {
+    let (i, value) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, direction) = opt(one_of("EW"))(i)?;
+    Ok((
+        i,
+        value.map(|value| match direction {
+            Some('W') => -value,
+            _ => value,
+        }),
+    ))
+}
+
+fn do_parse_hdg(i: &[u8]) -> IResult<&[u8], HdgData> {
+    let (i, heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, deviation) = parse_east_west(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, variation) = parse_east_west(i)?;
+    Ok((
+        i,
+        HdgData {
+            heading,
+            deviation,
+            variation,
+        },
+    ))
+}
+
+/// Parse HDG message
+/// from gpsd:
+/// $--HDG,x.x,x.x,a,x.x,a*hh
+/// 1     x.x   Magnetic Sensor heading in degrees
+/// 2     x.x   Magnetic Deviation, degrees
+/// 3     a     Magnetic Deviation direction, E = Easterly, W = Westerly
+/// 4     x.x   Magnetic Variation degrees
+/// 5     a     Magnetic Variation direction, E = Easterly, W = Westerly
+///
+/// Magnetic heading is sensor heading plus easterly deviation, true heading
+/// is magnetic heading plus easterly variation.
+pub fn parse_hdg(sentence: NmeaSentence) -> Result<HdgData, NmeaError> {
+    if sentence.message_id != b"HDG" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"HDG",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_hdg(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_hdg() {
+        let s = parse_nmea_sentence(b"$HCHDG,98.3,0.0,E,12.6,W*57").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let hdg = parse_hdg(s).unwrap();
+        assert_eq!(
+            HdgData {
+                heading: Some(98.3),
+                deviation: Some(0.),
+                variation: Some(-12.6),
+            },
+            hdg
+        );
+        assert_relative_eq!(hdg.magnetic_heading().unwrap(), 98.3);
+        assert_relative_eq!(hdg.true_heading().unwrap(), 85.7);
+
+        let s = parse_nmea_sentence(b"$HCHDG,355.0,2.0,E,7.5,E*41").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let hdg = parse_hdg(s).unwrap();
+        assert_relative_eq!(hdg.magnetic_heading().unwrap(), 357.);
+        assert_relative_eq!(hdg.true_heading().unwrap(), 4.5);
+
+        let s = parse_nmea_sentence(b"$HCHDG,101.1,,,,*43").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let hdg = parse_hdg(s).unwrap();
+        assert_eq!(hdg.deviation, None);
+        assert_eq!(hdg.variation, None);
+        assert_relative_eq!(hdg.magnetic_heading().unwrap(), 101.1);
+        assert_eq!(hdg.true_heading(), None);
+    }
+}
@@ -0,0 +1,57 @@
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct HdmData {
+    /// Heading, degrees Magnetic
+    pub heading: Option<f32>,
+}
+
+fn do_parse_hdm(i: &[u8]) -> IResult<&[u8], HdmData> {
+    let (i, heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    Ok((i, HdmData { heading }))
+}
+
+/// Parse HDM message
+/// from gpsd:
+/// $--HDM,x.x,M*hh
+/// 1     x.x   Heading, degrees Magnetic
+/// 2     M     M = Magnetic
+pub fn parse_hdm(sentence: NmeaSentence) -> Result<HdmData, NmeaError> {
+    if sentence.message_id != b"HDM" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"HDM",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_hdm(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_hdm() {
+        let s = parse_nmea_sentence(b"$HCHDM,238.5,M*25").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            HdmData {
+                heading: Some(238.5)
+            },
+            parse_hdm(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$HCHDM,,M*07").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(HdmData { heading: None }, parse_hdm(s).unwrap());
+    }
+}
@@ -0,0 +1,57 @@
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct HdtData {
+    /// Heading, degrees True
+    pub heading: Option<f32>,
+}
+
+fn do_parse_hdt(i: &[u8]) -> IResult<&[u8], HdtData> {
+    let (i, heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('T'))(i)?;
+    Ok((i, HdtData { heading }))
+}
+
+/// Parse HDT message
+/// from gpsd:
+/// $--HDT,x.x,T*hh
+/// 1     x.x   Heading, degrees True
+/// 2     T     T = True
+pub fn parse_hdt(sentence: NmeaSentence) -> Result<HdtData, NmeaError> {
+    if sentence.message_id != b"HDT" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"HDT",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_hdt(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_hdt() {
+        let s = parse_nmea_sentence(b"$GPHDT,274.07,T*03").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            HdtData {
+                heading: Some(274.07)
+            },
+            parse_hdt(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$GPHDT,,T*1B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(HdtData { heading: None }, parse_hdt(s).unwrap());
+    }
+}
@@ -0,0 +1,171 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::hdg::parse_east_west;
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum HeadingReference {
+    True,
+    Magnetic,
+}
+
+/// Reading of one of the two monitored heading sensors
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct HeadingSensorReading {
+    /// Heading, degrees
+    pub heading: Option<f32>,
+    pub valid: bool,
+    pub reference: Option<HeadingReference>,
+    /// Deviation of a magnetic sensor, degrees, East is positive
+    pub deviation: Option<f32>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct HmrData {
+    pub sensor1_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub sensor2_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    /// Maximum difference set by HMS, degrees
+    pub set_difference: Option<f32>,
+    /// Actual difference between the two headings, degrees
+    pub actual_difference: Option<f32>,
+    /// Warning flag, None if not reported
+    pub within_limit: Option<bool>,
+    pub sensor1: HeadingSensorReading,
+    pub sensor2: HeadingSensorReading,
+    /// Magnetic variation, degrees, East is positive
+    pub variation: Option<f32>,
+}
+
+fn parse_sensor_reading(i: &[u8]) -> IResult<&[u8], HeadingSensorReading> {
+    let (i, heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, status) = opt(one_of("AV"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, reference) = opt(one_of("MT"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, deviation) = parse_east_west(i)?;
+    Ok((
+        i,
+        HeadingSensorReading {
+            heading,
+            valid: status == Some('A'),
+            reference: reference.map(|reference| match reference {
+                'M' => HeadingReference::Magnetic,
+                'T' => HeadingReference::True,
+                _ => unreachable!(),
+            }),
+            deviation,
+        },
+    ))
+}
+
+fn do_parse_hmr(i: &[u8]) -> Result<HmrData, NmeaError<'_>> {
+    let (i, sensor1_id) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, sensor2_id) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, set_difference) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, actual_difference) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, warning) = opt(one_of("AV"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, sensor1) = parse_sensor_reading(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, sensor2) = parse_sensor_reading(i)?;
+    let (i, _) = char(',')(i)?;
+    let (_i, variation) = parse_east_west(i)?;
+    Ok(HmrData {
+        sensor1_id: array_string(sensor1_id)?,
+        sensor2_id: array_string(sensor2_id)?,
+        set_difference,
+        actual_difference,
+        within_limit: warning.map(|warning| warning == 'A'),
+        sensor1,
+        sensor2,
+        variation,
+    })
+}
+
+/// Parse HMR message, the report of a heading monitor
+/// $--HMR,c--c,c--c,x.x,x.x,A,x.x,A,a,x.x,a,x.x,A,a,x.x,a,x.x,a*hh
+/// 1     c--c  Heading sensor 1 ID
+/// 2     c--c  Heading sensor 2 ID
+/// 3     x.x   Set difference by HMS, degrees
+/// 4     x.x   Actual heading sensor difference, degrees
+/// 5     A     Warning flag, A = within set limit, V = set limit exceeded
+/// 6     x.x   Heading reading sensor 1, degrees
+/// 7     A     Status heading sensor 1, A = data valid, V = invalid
+/// 8     a     Sensor 1 type, M = magnetic, T = true
+/// 9     x.x   Deviation sensor 1, degrees
+/// 10    a     E or W
+/// 11    x.x   Heading reading sensor 2, degrees
+/// 12    A     Status heading sensor 2, A = data valid, V = invalid
+/// 13    a     Sensor 2 type, M = magnetic, T = true
+/// 14    x.x   Deviation sensor 2, degrees
+/// 15    a     E or W
+/// 16    x.x   Variation, degrees
+/// 17    a     E or W
+pub fn parse_hmr(sentence: NmeaSentence) -> Result<HmrData, NmeaError> {
+    if sentence.message_id != b"HMR" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"HMR",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_hmr(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_hmr() {
+        let s =
+            parse_nmea_sentence(b"$HCHMR,HDG1,HDG2,5.0,2.1,A,123.4,A,M,1.5,E,125.5,A,T,,,3.2,W*3D")
+                .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            HmrData {
+                sensor1_id: Some(ArrayString::from("HDG1").unwrap()),
+                sensor2_id: Some(ArrayString::from("HDG2").unwrap()),
+                set_difference: Some(5.),
+                actual_difference: Some(2.1),
+                within_limit: Some(true),
+                sensor1: HeadingSensorReading {
+                    heading: Some(123.4),
+                    valid: true,
+                    reference: Some(HeadingReference::Magnetic),
+                    deviation: Some(1.5),
+                },
+                sensor2: HeadingSensorReading {
+                    heading: Some(125.5),
+                    valid: true,
+                    reference: Some(HeadingReference::True),
+                    deviation: None,
+                },
+                variation: Some(-3.2),
+            },
+            parse_hmr(s).unwrap()
+        );
+
+        let s =
+            parse_nmea_sentence(b"$HCHMR,HDG1,HDG2,,6.0,,120.0,A,T,,,126.0,A,T,,,,*5D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let hmr = parse_hmr(s).unwrap();
+        assert_eq!(hmr.set_difference, None);
+        assert_eq!(hmr.within_limit, None);
+        assert_eq!(hmr.variation, None);
+    }
+}
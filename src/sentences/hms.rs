@@ -0,0 +1,67 @@
+use arrayvec::ArrayString;
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct HmsData {
+    pub sensor1_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub sensor2_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    /// Maximum difference allowed between the two headings, degrees
+    pub max_difference: Option<f32>,
+}
+
+fn do_parse_hms_sentence(i: &[u8]) -> Result<HmsData, NmeaError<'_>> {
+    let (i, sensor1_id) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, sensor2_id) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    let (_i, max_difference) = opt(float)(i)?;
+    Ok(HmsData {
+        sensor1_id: array_string(sensor1_id)?,
+        sensor2_id: array_string(sensor2_id)?,
+        max_difference,
+    })
+}
+
+/// Parse HMS message, the set up of a heading monitor
+/// $--HMS,c--c,c--c,x.x*hh
+/// 1     c--c  Heading sensor 1 ID
+/// 2     c--c  Heading sensor 2 ID
+/// 3     x.x   Maximum difference, degrees
+pub fn parse_hms_sentence(sentence: NmeaSentence) -> Result<HmsData, NmeaError> {
+    if sentence.message_id != b"HMS" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"HMS",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_hms_sentence(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_hms_sentence() {
+        let s = parse_nmea_sentence(b"$HCHMS,HDG1,HDG2,5.0*59").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            HmsData {
+                sensor1_id: Some(ArrayString::from("HDG1").unwrap()),
+                sensor2_id: Some(ArrayString::from("HDG2").unwrap()),
+                max_difference: Some(5.),
+            },
+            parse_hms_sentence(s).unwrap()
+        );
+    }
+}
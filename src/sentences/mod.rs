@@ -1,18 +1,125 @@
+mod aam;
+mod ais;
+mod alm;
+mod apb;
+mod bod;
 mod bwc;
+mod bww;
+mod dbk;
+mod dbs;
+mod dbt;
+mod dpt;
+mod dtm;
+mod gbs;
 mod gga;
 mod gll;
+mod gns;
+mod grs;
 mod gsa;
+mod gst;
 mod gsv;
+mod hdg;
+mod hdm;
+mod hdt;
+mod hmr;
+mod hms;
+mod mtw;
+mod mwd;
+mod mwv;
+mod pgrm;
+mod pmtk;
+mod proprietary;
+mod psti;
+mod pubx;
+mod rmb;
 mod rmc;
+mod rot;
+mod rpm;
+mod rsa;
+mod rte;
+mod tll;
+mod ttm;
 mod txt;
 mod utils;
+mod vbw;
+mod vdm;
+mod vhw;
+mod vlw;
+mod vpw;
 mod vtg;
+mod vwr;
+mod wcv;
+mod wnc;
+mod wpl;
+mod xdr;
+mod xte;
+mod zda;
+mod zdl;
+mod zfo;
+mod ztg;
 
-pub use bwc::{parse_bwc, BwcData};
+pub use aam::{parse_aam, AamData};
+pub use ais::{
+    decode_ais_payload, AisClassBExtendedPositionReport, AisClassBPositionReport, AisDimensions,
+    AisMessage, AisNavigationStatus, AisPositionReport, AisStaticAndVoyageData, AisStaticDataPart,
+    AisStaticDataReport,
+};
+pub use alm::{parse_alm, AlmData};
+pub use apb::{parse_apb, ApbBearing, ApbData};
+pub use bod::{parse_bod, BodData};
+pub use bwc::{parse_bwc, parse_bwr, BwcData};
+pub use bww::{parse_bww, BwwData};
+pub use dbk::{parse_dbk, DbkData};
+pub use dbs::{parse_dbs, DbsData};
+pub use dbt::{parse_dbt, DbtData};
+pub use dpt::{parse_dpt, DptData};
+pub use dtm::{parse_dtm, DtmData};
+pub use gbs::{parse_gbs, GbsData, IntegrityStatus};
 pub use gga::{parse_gga, GgaData};
 pub use gll::{parse_gll, GllData};
+pub use gns::{parse_gns, GnsData, GnsMode, GnsNavStatus};
+pub use grs::{parse_grs, GrsData, GrsMode};
 pub use gsa::{parse_gsa, GsaData};
+pub use gst::{parse_gst, GstData};
 pub use gsv::{parse_gsv, GsvData};
+pub use hdg::{parse_hdg, HdgData};
+pub use hdm::{parse_hdm, HdmData};
+pub use hdt::{parse_hdt, HdtData};
+pub use hmr::{parse_hmr, HeadingReference, HeadingSensorReading, HmrData};
+pub use hms::{parse_hms_sentence, HmsData};
+pub use mtw::{parse_mtw, MtwData};
+pub use mwd::{parse_mwd, MwdData};
+pub use mwv::{parse_mwv, MwvData, MwvReference};
+pub use pgrm::{parse_pgrme, parse_pgrmm, parse_pgrmz, PgrmeData, PgrmmData, PgrmzData};
+pub use pmtk::{parse_pmtk, PmtkAckData, PmtkAckFlag, PmtkCommand, PmtkData, PmtkReleaseData};
+pub use proprietary::{parse_proprietary, ProprietarySentence};
+pub use psti::{parse_psti, PstiAttitudeData, PstiBaselineData, PstiData, PstiRtkPositionData};
+pub use pubx::{
+    parse_pubx, PubxData, PubxNavStatus, PubxPositionData, PubxSatellite, PubxSatelliteStatus,
+    PubxSatellitesData, PubxTimeData,
+};
+pub use rmb::{parse_rmb, RmbData, SteerDirection};
 pub use rmc::{parse_rmc, RmcData, RmcStatusOfFix};
+pub use rot::{parse_rot, RotData};
+pub use rpm::{parse_rpm, RpmData, RpmSource};
+pub use rsa::{parse_rsa, RsaData};
+pub use rte::{parse_rte, RteData, RteMode};
+pub use tll::{parse_tll, TllData};
+pub use ttm::{parse_ttm, TargetAcquisition, TargetReference, TargetStatus, TtmData};
 pub use txt::{parse_txt, TxtData};
+pub use vbw::{parse_vbw, VbwData};
+pub use vdm::{parse_vdm, parse_vdo, VdmData};
+pub use vhw::{parse_vhw, VhwData};
+pub use vlw::{parse_vlw, VlwData};
+pub use vpw::{parse_vpw, VpwData};
 pub use vtg::{parse_vtg, VtgData};
+pub use vwr::{parse_vwr, VwrData};
+pub use wcv::{parse_wcv, WcvData};
+pub use wnc::{parse_wnc, WncData};
+pub use wpl::{parse_wpl, WplData};
+pub use xdr::{parse_xdr, XdrData, XdrMeasurement, XdrTransducer};
+pub use xte::{parse_xte, XteData};
+pub use zda::{parse_zda, ZdaData};
+pub use zdl::{parse_zdl, ZdlData, ZdlPointType};
+pub use zfo::{parse_zfo, ZfoData};
+pub use ztg::{parse_ztg, ZtgData};
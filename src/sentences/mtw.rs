@@ -0,0 +1,72 @@
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct MtwData {
+    /// Water temperature, degrees Celsius
+    pub temperature: Option<f32>,
+}
+
+fn do_parse_mtw(i: &[u8]) -> IResult<&[u8], MtwData> {
+    let (i, temperature) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, unit) = opt(one_of("CF"))(i)?;
+    Ok((
+        i,
+        MtwData {
+            temperature: temperature.map(|temperature| match unit {
+                Some('F') => (temperature - 32.) * 5. / 9.,
+                _ => temperature,
+            }),
+        },
+    ))
+}
+
+/// Parse MTW message
+/// from gpsd:
+/// $--MTW,x.x,C*hh
+/// 1     x.x   Degrees
+/// 2     C     Unit of Measurement, Celcius
+///
+/// Temperatures in Fahrenheit are normalised to degrees Celsius.
+pub fn parse_mtw(sentence: NmeaSentence) -> Result<MtwData, NmeaError> {
+    if sentence.message_id != b"MTW" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"MTW",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_mtw(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_mtw() {
+        let s = parse_nmea_sentence(b"$IIMTW,17.9,C*1C").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            MtwData {
+                temperature: Some(17.9)
+            },
+            parse_mtw(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$IIMTW,64.4,F*10").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_relative_eq!(parse_mtw(s).unwrap().temperature.unwrap(), 18.);
+
+        let s = parse_nmea_sentence(b"$IIMTW,,C*0D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(MtwData { temperature: None }, parse_mtw(s).unwrap());
+    }
+}
@@ -0,0 +1,94 @@
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::sentences::utils::speed_to_knots;
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct MwdData {
+    /// Direction the wind is blowing from, degrees True
+    pub wind_direction_true: Option<f32>,
+    /// Direction the wind is blowing from, degrees Magnetic
+    pub wind_direction_magnetic: Option<f32>,
+    /// Wind speed, knots
+    pub wind_speed: Option<f32>,
+}
+
+fn do_parse_mwd(i: &[u8]) -> IResult<&[u8], MwdData> {
+    let (i, wind_direction_true) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('T'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, wind_direction_magnetic) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_knots) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_mps) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    Ok((
+        i,
+        MwdData {
+            wind_direction_true,
+            wind_direction_magnetic,
+            wind_speed: speed_knots.or_else(|| speed_mps.map(|speed| speed_to_knots(speed, 'M'))),
+        },
+    ))
+}
+
+/// Parse MWD message
+/// from gpsd:
+/// $--MWD,x.x,T,x.x,M,x.x,N,x.x,M*hh
+/// 1     x.x   Wind direction, 0 to 359 degrees True
+/// 2     T     T = True
+/// 3     x.x   Wind direction, 0 to 359 degrees Magnetic
+/// 4     M     M = Magnetic
+/// 5     x.x   Wind speed, knots
+/// 6     N     N = knots
+/// 7     x.x   Wind speed, meters/second
+/// 8     M     M = m/s
+///
+/// Wind speed is normalised to knots.
+pub fn parse_mwd(sentence: NmeaSentence) -> Result<MwdData, NmeaError> {
+    if sentence.message_id != b"MWD" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"MWD",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_mwd(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_mwd() {
+        let s = parse_nmea_sentence(b"$WIMWD,270.0,T,275.5,M,12.0,N,6.2,M*6D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            MwdData {
+                wind_direction_true: Some(270.),
+                wind_direction_magnetic: Some(275.5),
+                wind_speed: Some(12.),
+            },
+            parse_mwd(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$WIMWD,,T,,M,,N,5.0,M*71").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let mwd = parse_mwd(s).unwrap();
+        assert_eq!(mwd.wind_direction_true, None);
+        assert_relative_eq!(mwd.wind_speed.unwrap(), 9.719222);
+    }
+}
@@ -0,0 +1,112 @@
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::sentences::utils::speed_to_knots;
+use crate::{parse::NmeaSentence, NmeaError};
+
+/// Reference of the wind angle of an MWV sentence
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MwvReference {
+    /// Apparent wind, relative to the bow of the vessel
+    Relative,
+    /// True wind, relative to the bow of the vessel
+    Theoretical,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MwvData {
+    /// Wind angle, degrees clockwise from the bow
+    pub wind_angle: Option<f32>,
+    pub reference: Option<MwvReference>,
+    /// Wind speed, knots
+    pub wind_speed: Option<f32>,
+    pub valid: bool,
+}
+
+fn do_parse_mwv(i: &[u8]) -> IResult<&[u8], MwvData> {
+    let (i, wind_angle) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, reference) = opt(one_of("RT"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, wind_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, units) = opt(one_of("KMNS"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, status) = one_of("AV")(i)?;
+    Ok((
+        i,
+        MwvData {
+            wind_angle,
+            reference: reference.map(|reference| match reference {
+                'R' => MwvReference::Relative,
+                'T' => MwvReference::Theoretical,
+                _ => unreachable!(),
+            }),
+            wind_speed: wind_speed.map(|speed| speed_to_knots(speed, units.unwrap_or('N'))),
+            valid: status == 'A',
+        },
+    ))
+}
+
+/// Parse MWV message
+/// from gpsd:
+/// $--MWV,x.x,a,x.x,a,A*hh
+/// 1     x.x   Wind Angle, 0 to 359 degrees
+/// 2     a     Reference, R = Relative, T = True
+/// 3     x.x   Wind Speed
+/// 4     a     Wind Speed Units, K = km/h, M = m/s, N = knots, S = statute mph
+/// 5     A     Status, A = Data Valid, V = Invalid
+///
+/// Wind speed is normalised to knots.
+pub fn parse_mwv(sentence: NmeaSentence) -> Result<MwvData, NmeaError> {
+    if sentence.message_id != b"MWV" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"MWV",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_mwv(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_mwv() {
+        let s = parse_nmea_sentence(b"$WIMWV,214.8,R,0.1,K,A*28").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let mwv = parse_mwv(s).unwrap();
+        assert_eq!(mwv.wind_angle, Some(214.8));
+        assert_eq!(mwv.reference, Some(MwvReference::Relative));
+        assert_relative_eq!(mwv.wind_speed.unwrap(), 0.1 / 1.852);
+        assert!(mwv.valid);
+
+        let s = parse_nmea_sentence(b"$IIMWV,041.0,T,10.5,N,A*0A").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let mwv = parse_mwv(s).unwrap();
+        assert_eq!(mwv.reference, Some(MwvReference::Theoretical));
+        assert_eq!(mwv.wind_speed, Some(10.5));
+
+        let s = parse_nmea_sentence(b"$WIMWV,090.0,R,10.0,M,A*18").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_relative_eq!(parse_mwv(s).unwrap().wind_speed.unwrap(), 19.438445);
+
+        let s = parse_nmea_sentence(b"$WIMWV,,R,,N,V*34").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            MwvData {
+                wind_angle: None,
+                reference: Some(MwvReference::Relative),
+                wind_speed: None,
+                valid: false,
+            },
+            parse_mwv(s).unwrap()
+        );
+    }
+}
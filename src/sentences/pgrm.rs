@@ -0,0 +1,187 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 32;
+const FEET_TO_METERS: f32 = 0.3048;
+
+/// Estimated position errors, in meters
+#[derive(Debug, PartialEq, Clone)]
+pub struct PgrmeData {
+    pub horizontal: Option<f32>,
+    pub vertical: Option<f32>,
+    pub spherical: Option<f32>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PgrmzData {
+    /// Barometric altitude, normalised to meters
+    pub altitude: Option<f32>,
+    /// 2 for a 2D fix with a user altitude, 3 for a 3D fix with a GPS altitude
+    pub fix_dimension: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PgrmmData {
+    pub datum: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+fn check_pgrm<'a>(
+    sentence: &NmeaSentence<'a>,
+    message_id: &'static [u8],
+) -> Result<(), NmeaError<'a>> {
+    if sentence.manufacturer != Some(b"GRM") {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"GRM",
+            found: sentence.manufacturer.unwrap_or(sentence.talker_id),
+        })
+    } else if sentence.message_id != message_id {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: message_id,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn parse_error_field(i: &[u8]) -> IResult<&[u8], Option<f32>> {
+    let (i, error) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    Ok((i, error))
+}
+
+fn do_parse_pgrme(i: &[u8]) -> IResult<&[u8], PgrmeData> {
+    // 1. - 2. Horizontal position error, M
+    let (i, horizontal) = parse_error_field(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. - 4. Vertical position error, M
+    let (i, vertical) = parse_error_field(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. - 6. Spherical position error, M
+    let (i, spherical) = parse_error_field(i)?;
+    Ok((
+        i,
+        PgrmeData {
+            horizontal,
+            vertical,
+            spherical,
+        },
+    ))
+}
+
+fn do_parse_pgrmz(i: &[u8]) -> IResult<&[u8], PgrmzData> {
+    // 1. Altitude
+    let (i, altitude) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Unit, f = feet, some units send M
+    let (i, unit) = opt(one_of("fFM"))(i)?;
+    // 3. Position fix dimensions
+    let (i, fix_dimension) = opt(preceded(char(','), opt(one_of("23"))))(i)?;
+    Ok((
+        i,
+        PgrmzData {
+            altitude: altitude.map(|altitude| match unit {
+                Some('M') => altitude,
+                _ => altitude * FEET_TO_METERS,
+            }),
+            fix_dimension: fix_dimension.flatten().map(|c| c as u8 - b'0'),
+        },
+    ))
+}
+
+/// Parse Garmin PGRME message
+/// from the Garmin technical specifications:
+/// $PGRME,x.x,M,x.x,M,x.x,M*hh
+/// 1,2   x.x,M  Estimated horizontal position error, meters
+/// 3,4   x.x,M  Estimated vertical position error, meters
+/// 5,6   x.x,M  Estimated position error, meters
+pub fn parse_pgrme(sentence: NmeaSentence) -> Result<PgrmeData, NmeaError> {
+    check_pgrm(&sentence, b"E")?;
+    Ok(do_parse_pgrme(sentence.data)?.1)
+}
+
+/// Parse Garmin PGRMZ message
+/// from the Garmin technical specifications:
+/// $PGRMZ,x.x,f,x*hh
+/// 1     x.x  Altitude, from the barometric altimeter when the unit has one
+/// 2     f    Unit, feet
+/// 3     x    Position fix dimensions, 2 = user altitude, 3 = GPS altitude
+pub fn parse_pgrmz(sentence: NmeaSentence) -> Result<PgrmzData, NmeaError> {
+    check_pgrm(&sentence, b"Z")?;
+    Ok(do_parse_pgrmz(sentence.data)?.1)
+}
+
+/// Parse Garmin PGRMM message
+/// from the Garmin technical specifications:
+/// $PGRMM,c--c*hh
+/// 1     c--c  Name of the currently active map datum
+pub fn parse_pgrmm(sentence: NmeaSentence) -> Result<PgrmmData, NmeaError> {
+    check_pgrm(&sentence, b"M")?;
+    let (_i, datum) = parse_str_field(sentence.data)?;
+    Ok(PgrmmData {
+        datum: array_string(datum)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_pgrme() {
+        let s = parse_nmea_sentence(b"$PGRME,15.0,M,45.0,M,25.0,M*1C").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            parse_pgrme(s).unwrap(),
+            PgrmeData {
+                horizontal: Some(15.),
+                vertical: Some(45.),
+                spherical: Some(25.),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_pgrmz() {
+        let s = parse_nmea_sentence(b"$PGRMZ,246,f,3*1B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = parse_pgrmz(s).unwrap();
+        assert_relative_eq!(data.altitude.unwrap(), 74.9808);
+        assert_eq!(data.fix_dimension, Some(3));
+
+        let s = parse_nmea_sentence(b"$PGRMZ,93,M,2*0B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            parse_pgrmz(s).unwrap(),
+            PgrmzData {
+                altitude: Some(93.),
+                fix_dimension: Some(2),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_pgrmm() {
+        let s = parse_nmea_sentence(b"$PGRMM,NAD27 Canada*2F").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(&parse_pgrmm(s).unwrap().datum.unwrap(), "NAD27 Canada");
+
+        let s = parse_nmea_sentence(b"$PGRMZ,246,f,3*1B").unwrap();
+        assert!(matches!(
+            parse_pgrmm(s),
+            Err(NmeaError::WrongSentenceHeader { .. })
+        ));
+    }
+}
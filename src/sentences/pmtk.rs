@@ -0,0 +1,325 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, one_of};
+use nom::combinator::{map_res, opt, rest};
+use nom::multi::count;
+use nom::sequence::preceded;
+
+use crate::parse::{checksum, NmeaSentence};
+use crate::sentences::utils::{array_string, number, parse_num, parse_str_field};
+use crate::{NmeaError, SentenceMask, SentenceType};
+
+const MAX_LEN: usize = 32;
+
+/// Sentences in the order of the fields of PMTK314 and PMTK514, None for the
+/// reserved fields and the MTK specific sentences
+const OUTPUT_ORDER: [Option<SentenceType>; 19] = [
+    Some(SentenceType::GLL),
+    Some(SentenceType::RMC),
+    Some(SentenceType::VTG),
+    Some(SentenceType::GGA),
+    Some(SentenceType::GSA),
+    Some(SentenceType::GSV),
+    Some(SentenceType::GRS),
+    Some(SentenceType::GST),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(SentenceType::ZDA),
+    None,
+];
+
+/// Result of a command, as reported by PMTK001
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PmtkAckFlag {
+    InvalidCommand,
+    UnsupportedCommand,
+    /// Valid command, but the action failed
+    Failed,
+    Succeeded,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PmtkAckData {
+    /// Type of the acknowledged command, e.g. 220 for PMTK220
+    pub command: u16,
+    pub flag: PmtkAckFlag,
+}
+
+/// Firmware release, PMTK705
+#[derive(Debug, PartialEq, Clone)]
+pub struct PmtkReleaseData {
+    pub release: ArrayString<[u8; MAX_LEN]>,
+    pub build_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub product_model: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub sdk_version: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PmtkData {
+    /// PMTK001
+    Ack(PmtkAckData),
+    /// PMTK500, fix interval in milliseconds
+    UpdateRate(u32),
+    /// PMTK514, sentences output at every fix or every few fixes
+    OutputSentences(SentenceMask),
+    /// PMTK705
+    Release(PmtkReleaseData),
+}
+
+fn do_parse_ack(i: &[u8]) -> Result<PmtkAckData, NmeaError<'_>> {
+    // 1. Command
+    let (i, command) = number::<u16>(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Flag
+    let (_i, flag) = one_of("0123")(i)?;
+
+    Ok(PmtkAckData {
+        command,
+        flag: match flag {
+            '0' => PmtkAckFlag::InvalidCommand,
+            '1' => PmtkAckFlag::UnsupportedCommand,
+            '2' => PmtkAckFlag::Failed,
+            '3' => PmtkAckFlag::Succeeded,
+            _ => unreachable!(),
+        },
+    })
+}
+
+fn do_parse_update_rate(i: &[u8]) -> Result<u32, NmeaError<'_>> {
+    // 1. Fix interval, ms, followed by reserved fields
+    let (_i, fix_interval) = number::<u32>(i)?;
+    Ok(fix_interval)
+}
+
+fn do_parse_output_sentences(i: &[u8]) -> Result<SentenceMask, NmeaError<'_>> {
+    // 1. - 19. Output rate of each sentence, in fixes, 0 = disabled
+    let (i, first) = number::<u8>(i)?;
+    let (_i, rest) = count(preceded(char(','), number::<u8>), OUTPUT_ORDER.len() - 1)(i)?;
+
+    let mut sentences = SentenceMask::default();
+    for (sentence_type, rate) in OUTPUT_ORDER.iter().zip(core::iter::once(first).chain(rest)) {
+        if let (Some(sentence_type), true) = (sentence_type, rate != 0) {
+            sentences.insert(*sentence_type);
+        }
+    }
+    Ok(sentences)
+}
+
+fn do_parse_release(i: &[u8]) -> Result<PmtkReleaseData, NmeaError<'_>> {
+    // 1. Release string
+    let (i, release) = parse_str_field(i)?;
+    // 2. Build ID
+    let (i, build_id) = opt(preceded(char(','), parse_str_field))(i)?;
+    // 3. Product model
+    let (i, product_model) = opt(preceded(char(','), parse_str_field))(i)?;
+    // 4. SDK version
+    let (_i, sdk_version) = opt(preceded(char(','), parse_str_field))(i)?;
+
+    Ok(PmtkReleaseData {
+        release: array_string(release)?.unwrap_or_default(),
+        build_id: array_string(build_id.flatten())?,
+        product_model: array_string(product_model.flatten())?,
+        sdk_version: array_string(sdk_version.flatten())?,
+    })
+}
+
+/// Parse MediaTek PMTK message, the packet type follows the mnemonic
+/// from the MTK NMEA packet user manual:
+/// $PMTK001,x,x*hh            Acknowledge, command type and flag:
+///                            0 = invalid command, 1 = unsupported command,
+///                            2 = valid command but action failed,
+///                            3 = valid command and action succeeded
+/// $PMTK500,x,x,x,x,x*hh      Fix interval in ms, response to PMTK400
+/// $PMTK514,x,...,x*hh        Output rate of GLL, RMC, VTG, GGA, GSA, GSV,
+///                            GRS, GST, 9 reserved and MTK fields, ZDA and
+///                            MCHN, response to PMTK414
+/// $PMTK705,c--c,c--c,c--c*hh Firmware release, build ID and product model,
+///                            response to PMTK605
+pub fn parse_pmtk(sentence: NmeaSentence) -> Result<PmtkData, NmeaError> {
+    if sentence.manufacturer != Some(b"MTK") {
+        return Err(NmeaError::WrongSentenceHeader {
+            expected: b"MTK",
+            found: sentence.manufacturer.unwrap_or(sentence.talker_id),
+        });
+    }
+    let (_, packet_type) = map_res(rest, parse_num::<u16>)(sentence.message_id)?;
+    match packet_type {
+        1 => Ok(PmtkData::Ack(do_parse_ack(sentence.data)?)),
+        500 => Ok(PmtkData::UpdateRate(do_parse_update_rate(sentence.data)?)),
+        514 => Ok(PmtkData::OutputSentences(do_parse_output_sentences(
+            sentence.data,
+        )?)),
+        705 => Ok(PmtkData::Release(do_parse_release(sentence.data)?)),
+        _ => Err(NmeaError::Unsupported(SentenceType::PMTK)),
+    }
+}
+
+/// Command to configure a MediaTek receiver
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PmtkCommand {
+    /// Restart using all the data available
+    HotStart,
+    /// Restart without the ephemeris
+    WarmStart,
+    /// Restart without the time, position, almanac and ephemeris
+    ColdStart,
+    /// Cold start and reset the configuration to the factory defaults
+    FullColdStart,
+    /// Fix interval, milliseconds
+    SetUpdateRate(u32),
+    /// Output the given sentences at every fix, the others are disabled.
+    /// Sentences which MTK receivers do not output are left out.
+    SetOutput(SentenceMask),
+    /// Restore the default sentence output
+    SetDefaultOutput,
+    QueryUpdateRate,
+    QueryOutput,
+    QueryRelease,
+}
+
+impl PmtkCommand {
+    /// Build the sentence to send to the receiver, with its checksum and
+    /// the line ending
+    pub fn build(&self) -> String {
+        let body = match self {
+            PmtkCommand::HotStart => "PMTK101".to_owned(),
+            PmtkCommand::WarmStart => "PMTK102".to_owned(),
+            PmtkCommand::ColdStart => "PMTK103".to_owned(),
+            PmtkCommand::FullColdStart => "PMTK104".to_owned(),
+            PmtkCommand::SetUpdateRate(fix_interval) => format!("PMTK220,{}", fix_interval),
+            PmtkCommand::SetOutput(sentences) => {
+                OUTPUT_ORDER
+                    .iter()
+                    .fold("PMTK314".to_owned(), |mut body, sentence_type| {
+                        let enabled = sentence_type.is_some_and(|t| sentences.contains(&t));
+                        body.push_str(if enabled { ",1" } else { ",0" });
+                        body
+                    })
+            }
+            PmtkCommand::SetDefaultOutput => "PMTK314,-1".to_owned(),
+            PmtkCommand::QueryUpdateRate => "PMTK400".to_owned(),
+            PmtkCommand::QueryOutput => "PMTK414".to_owned(),
+            PmtkCommand::QueryRelease => "PMTK605".to_owned(),
+        };
+        format!("${}*{:02X}\r\n", body, checksum(body.as_bytes().iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{parse, parse_nmea_sentence};
+
+    #[test]
+    fn test_parse_pmtk_ack() {
+        let s = parse_nmea_sentence(b"$PMTK001,604,3*32").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            parse_pmtk(s).unwrap(),
+            PmtkData::Ack(PmtkAckData {
+                command: 604,
+                flag: PmtkAckFlag::Succeeded,
+            })
+        );
+
+        let s = parse_nmea_sentence(b"$PMTK001,220,2*31").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            parse_pmtk(s).unwrap(),
+            PmtkData::Ack(PmtkAckData {
+                command: 220,
+                flag: PmtkAckFlag::Failed,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_pmtk_responses() {
+        let s = parse_nmea_sentence(b"$PMTK500,1000,0,0,0,0*1A").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(parse_pmtk(s).unwrap(), PmtkData::UpdateRate(1000));
+
+        let s = parse_nmea_sentence(b"$PMTK514,0,1,1,1,1,5,0,0,0,0,0,0,0,0,0,0,0,0,0*2B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            parse_pmtk(s).unwrap(),
+            PmtkData::OutputSentences(
+                SentenceType::RMC
+                    | SentenceType::VTG
+                    | SentenceType::GGA
+                    | SentenceType::GSA
+                    | SentenceType::GSV
+            )
+        );
+
+        let s = parse_nmea_sentence(b"$PMTK705,AXN_2.31_3339_13101700,5632,PA6H,1.0*6B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = match parse_pmtk(s).unwrap() {
+            PmtkData::Release(data) => data,
+            data => panic!("Unexpected data {:?}", data),
+        };
+        assert_eq!(&data.release, "AXN_2.31_3339_13101700");
+        assert_eq!(&data.build_id.unwrap(), "5632");
+        assert_eq!(&data.product_model.unwrap(), "PA6H");
+        assert_eq!(&data.sdk_version.unwrap(), "1.0");
+
+        let s = parse_nmea_sentence(b"$PMTK010,001*2E").unwrap();
+        assert_eq!(
+            parse_pmtk(s),
+            Err(NmeaError::Unsupported(SentenceType::PMTK))
+        );
+    }
+
+    #[test]
+    fn test_build_pmtk_command() {
+        assert_eq!(PmtkCommand::HotStart.build(), "$PMTK101*32\r\n");
+        assert_eq!(PmtkCommand::ColdStart.build(), "$PMTK103*30\r\n");
+        assert_eq!(
+            PmtkCommand::SetUpdateRate(200).build(),
+            "$PMTK220,200*2C\r\n"
+        );
+        assert_eq!(
+            PmtkCommand::SetOutput(SentenceType::RMC | SentenceType::GGA | SentenceType::HDT)
+                .build(),
+            "$PMTK314,0,1,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0*28\r\n"
+        );
+        assert_eq!(PmtkCommand::SetDefaultOutput.build(), "$PMTK314,-1*04\r\n");
+    }
+
+    #[test]
+    fn test_pmtk_command_round_trip() {
+        let commands = [
+            (PmtkCommand::HotStart, "101", None),
+            (PmtkCommand::WarmStart, "102", None),
+            (PmtkCommand::ColdStart, "103", None),
+            (PmtkCommand::FullColdStart, "104", None),
+            (PmtkCommand::SetUpdateRate(1000), "220", Some("1000")),
+            (
+                PmtkCommand::SetOutput(SentenceType::GLL | SentenceType::ZDA),
+                "314",
+                Some("1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0"),
+            ),
+            (PmtkCommand::SetDefaultOutput, "314", Some("-1")),
+            (PmtkCommand::QueryUpdateRate, "400", None),
+            (PmtkCommand::QueryOutput, "414", None),
+            (PmtkCommand::QueryRelease, "605", None),
+        ];
+        for (command, message_id, data) in &commands {
+            let command = command.build();
+            assert!(command.ends_with("\r\n"));
+            let s = parse_nmea_sentence(command.trim_end().as_bytes()).unwrap();
+            assert_eq!(s.checksum, s.calc_checksum());
+            assert_eq!(s.manufacturer, Some(&b"MTK"[..]));
+            assert_eq!(s.message_id, message_id.as_bytes());
+            assert_eq!(s.has_data, data.is_some());
+            assert_eq!(s.data, data.unwrap_or_default().as_bytes());
+            assert!(parse(command.trim_end().as_bytes()).is_ok());
+        }
+    }
+}
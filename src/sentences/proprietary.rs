@@ -0,0 +1,132 @@
+use arrayvec::{Array, ArrayString};
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::array_string;
+use crate::NmeaError;
+
+const MAX_ID_LEN: usize = 16;
+
+/// A proprietary sentence, `$P` followed by the manufacturer mnemonic
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProprietarySentence {
+    /// Three letter manufacturer mnemonic, e.g. `UBX`, `MTK` or `GRM`
+    pub manufacturer: ArrayString<[u8; 3]>,
+    /// Characters between the mnemonic and the first comma, e.g. `001` for
+    /// `$PMTK001`, empty when the message type is given in the data fields
+    pub message_id: ArrayString<[u8; MAX_ID_LEN]>,
+    /// Data fields, without the checksum
+    pub data: String,
+}
+
+impl ProprietarySentence {
+    /// Iterate over the comma separated data fields
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.data.split(',')
+    }
+}
+
+fn field<A: Array<Item = u8> + Copy>(field: &[u8]) -> Result<ArrayString<A>, NmeaError<'_>> {
+    let field = core::str::from_utf8(field).map_err(|_| NmeaError::Utf8DecodingError)?;
+    Ok(array_string(Some(field))?.unwrap_or_default())
+}
+
+/// Parse any proprietary sentence, the data fields are kept as they are for
+/// decoders of the manufacturer
+pub fn parse_proprietary(sentence: NmeaSentence) -> Result<ProprietarySentence, NmeaError> {
+    let manufacturer = match sentence.manufacturer {
+        Some(manufacturer) => manufacturer,
+        None => {
+            return Err(NmeaError::WrongSentenceHeader {
+                expected: b"P",
+                found: sentence.talker_id,
+            })
+        }
+    };
+    Ok(ProprietarySentence {
+        manufacturer: field(manufacturer)?,
+        message_id: field(sentence.message_id)?,
+        data: core::str::from_utf8(sentence.data)
+            .map_err(|_| NmeaError::Utf8DecodingError)?
+            .to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{parse, parse_nmea_sentence, ParseResult};
+
+    #[test]
+    fn test_proprietary_framing() {
+        let s = parse_nmea_sentence(b"$PMTK001,604,3*32").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert!(s.is_proprietary());
+        assert_eq!(s.talker_id, b"P");
+        assert_eq!(s.manufacturer, Some(&b"MTK"[..]));
+        assert_eq!(s.message_id, b"001");
+        assert_eq!(s.data, b"604,3");
+
+        let s = parse_nmea_sentence(b"$PUBX,00,081350.00,4717.113210,N*5B").unwrap();
+        assert_eq!(s.manufacturer, Some(&b"UBX"[..]));
+        assert_eq!(s.message_id, b"");
+        assert_eq!(s.data, b"00,081350.00,4717.113210,N");
+
+        let s = parse_nmea_sentence(b"$PMTK101*32").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.message_id, b"101");
+        assert!(!s.has_data);
+        assert_eq!(s.data, b"");
+
+        let s = parse_nmea_sentence(b"$GPGGA,,,,,,0,,,,,,,,*66").unwrap();
+        assert!(!s.is_proprietary());
+        assert_eq!(s.talker_id, b"GP");
+        assert_eq!(s.message_id, b"GGA");
+    }
+
+    #[test]
+    fn test_parse_proprietary() {
+        let s = parse_nmea_sentence(b"$PGRME,15.0,M,45.0,M,25.0,M*1C").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = parse_proprietary(s).unwrap();
+        assert_eq!(&data.manufacturer, "GRM");
+        assert_eq!(&data.message_id, "E");
+        assert_eq!(
+            data.fields().collect::<Vec<_>>(),
+            ["15.0", "M", "45.0", "M", "25.0", "M"]
+        );
+
+        let s = parse_nmea_sentence(b"$GPGGA,,,,,,0,,,,,,,,*66").unwrap();
+        assert!(matches!(
+            parse_proprietary(s),
+            Err(NmeaError::WrongSentenceHeader { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_unknown_proprietary() {
+        assert_eq!(
+            parse(b"$PXYZ,1,2*00"),
+            Err(NmeaError::ChecksumMismatch {
+                calculated: 0x08,
+                found: 0
+            })
+        );
+        match parse(b"$PXYZ,1,2*08").unwrap() {
+            ParseResult::Proprietary(data) => {
+                assert_eq!(&data.manufacturer, "XYZ");
+                assert_eq!(&data.message_id, "");
+                assert_eq!(&data.data, "1,2");
+            }
+            result => panic!("Unexpected result {:?}", result),
+        }
+
+        let long = b"$PXYZ,100,101,102,103,104,105,106,107,108,109,110,111,112,113,114,115,116,117,118,119,120,121,122,123,124,125,126,127,128,129*0A";
+        match parse(long).unwrap() {
+            ParseResult::Proprietary(data) => {
+                assert_eq!(data.fields().count(), 30);
+                assert_eq!(data.fields().last(), Some("129"));
+            }
+            result => panic!("Unexpected result {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use chrono::{NaiveDate, NaiveTime};
+use nom::bytes::complete::take;
+use nom::character::complete::{char, one_of};
+use nom::combinator::{map_opt, opt};
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::gns::GnsMode;
+use crate::sentences::utils::{parse_date, parse_hms, parse_lat_lon};
+use crate::{FixType, NmeaError, SentenceType};
+
+/// RTK position, PSTI,030
+///
+/// Velocities are in meters per second.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PstiRtkPositionData {
+    pub fix_time: Option<NaiveTime>,
+    pub fix_date: Option<NaiveDate>,
+    pub valid: bool,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Meters above mean sea level
+    pub altitude: Option<f32>,
+    pub east_velocity: Option<f32>,
+    pub north_velocity: Option<f32>,
+    pub up_velocity: Option<f32>,
+    pub mode: Option<GnsMode>,
+    /// Age of the RTK corrections, seconds
+    pub rtk_age: Option<f32>,
+    /// Ratio of the ambiguity resolution, higher is more reliable
+    pub rtk_ratio: Option<f32>,
+}
+
+impl PstiRtkPositionData {
+    pub fn fix_type(&self) -> FixType {
+        match self.mode {
+            Some(mode) if self.valid => mode.into(),
+            _ => FixType::Invalid,
+        }
+    }
+}
+
+/// Baseline vector from the base to the rover antenna, PSTI,032
+///
+/// Projections and length are in meters, the course in degrees from true
+/// north.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PstiBaselineData {
+    pub fix_time: Option<NaiveTime>,
+    pub fix_date: Option<NaiveDate>,
+    pub valid: bool,
+    pub mode: Option<GnsMode>,
+    pub east: Option<f32>,
+    pub north: Option<f32>,
+    pub up: Option<f32>,
+    pub length: Option<f32>,
+    pub course: Option<f32>,
+}
+
+/// Attitude of a dual antenna receiver, PSTI,036
+///
+/// Angles are in degrees, the heading from true north.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PstiAttitudeData {
+    pub fix_time: Option<NaiveTime>,
+    pub fix_date: Option<NaiveDate>,
+    pub heading: Option<f32>,
+    pub pitch: Option<f32>,
+    pub roll: Option<f32>,
+    pub mode: Option<GnsMode>,
+}
+
+impl PstiAttitudeData {
+    /// True when the attitude comes from a solution with a fix
+    pub fn is_valid(&self) -> bool {
+        self.mode
+            .is_some_and(|mode| FixType::from(mode) != FixType::Invalid)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PstiData {
+    RtkPosition(PstiRtkPositionData),
+    Baseline(PstiBaselineData),
+    Attitude(PstiAttitudeData),
+}
+
+fn parse_mode(i: &[u8]) -> IResult<&[u8], Option<GnsMode>> {
+    opt(map_opt(take(1usize), |c: &[u8]| GnsMode::from_char(c[0])))(i)
+}
+
+fn parse_status(i: &[u8]) -> IResult<&[u8], bool> {
+    let (i, status) = one_of("AV")(i)?;
+    Ok((i, status == 'A'))
+}
+
+fn do_parse_rtk_position(i: &[u8]) -> IResult<&[u8], PstiRtkPositionData> {
+    // 1. UTC time
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Status, A = valid, V = void
+    let (i, valid) = parse_status(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. - 6. Latitude, N/S, longitude, E/W
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Altitude above mean sea level
+    let (i, altitude) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. - 10. East, north and up velocities
+    let (i, east_velocity) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, north_velocity) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, up_velocity) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. UTC date
+    let (i, fix_date) = opt(parse_date)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 12. Mode indicator
+    let (i, mode) = parse_mode(i)?;
+    let (i, _) = char(',')(i)?;
+    // 13. RTK age
+    let (i, rtk_age) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 14. RTK ratio
+    let (i, rtk_ratio) = opt(float)(i)?;
+
+    Ok((
+        i,
+        PstiRtkPositionData {
+            fix_time,
+            fix_date,
+            valid,
+            latitude: lat_lon.map(|v| v.0),
+            longitude: lat_lon.map(|v| v.1),
+            altitude,
+            east_velocity,
+            north_velocity,
+            up_velocity,
+            mode,
+            rtk_age,
+            rtk_ratio,
+        },
+    ))
+}
+
+fn do_parse_baseline(i: &[u8]) -> IResult<&[u8], PstiBaselineData> {
+    // 1. UTC time
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. UTC date
+    let (i, fix_date) = opt(parse_date)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Status, A = valid, V = void
+    let (i, valid) = parse_status(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Mode indicator
+    let (i, mode) = parse_mode(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. - 7. East, north and up projections of the baseline
+    let (i, east) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, north) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, up) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Baseline length
+    let (i, length) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Baseline course
+    let (i, course) = opt(float)(i)?;
+    // 10. - 14. Reserved
+
+    Ok((
+        i,
+        PstiBaselineData {
+            fix_time,
+            fix_date,
+            valid,
+            mode,
+            east,
+            north,
+            up,
+            length,
+            course,
+        },
+    ))
+}
+
+fn do_parse_attitude(i: &[u8]) -> IResult<&[u8], PstiAttitudeData> {
+    // 1. UTC time
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. UTC date
+    let (i, fix_date) = opt(parse_date)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Heading
+    let (i, heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Pitch
+    let (i, pitch) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Roll
+    let (i, roll) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Mode indicator
+    let (i, mode) = parse_mode(i)?;
+
+    Ok((
+        i,
+        PstiAttitudeData {
+            fix_time,
+            fix_date,
+            heading,
+            pitch,
+            roll,
+            mode,
+        },
+    ))
+}
+
+/// Parse SkyTraq PSTI message
+/// from the SkyTraq NMEA extensions:
+/// $PSTI,030,hhmmss.sss,A,ddmm.mmmmmmm,a,dddmm.mmmmmmm,a,x.x,x.x,x.x,x.x,ddmmyy,a,x.x,x.x*hh
+/// 1     030           Message ID, RTK position
+/// 2     hhmmss.sss    UTC time
+/// 3     A             Status, A = valid, V = void
+/// 4,5   ddmm.mmmmmmm  Latitude, N/S
+/// 6,7   dddmm.mmmmmmm Longitude, E/W
+/// 8     x.x           Altitude above mean sea level, meters
+/// 9-11  x.x           East, north and up velocities, m/s
+/// 12    ddmmyy        UTC date
+/// 13    a             Mode indicator, as in GNS
+/// 14    x.x           RTK age, seconds
+/// 15    x.x           RTK ratio
+///
+/// $PSTI,032,hhmmss.sss,ddmmyy,A,a,x.x,x.x,x.x,x.x,x.x,,,,,*hh
+/// 1     032           Message ID, baseline vector
+/// 2     hhmmss.sss    UTC time
+/// 3     ddmmyy        UTC date
+/// 4     A             Status, A = valid, V = void
+/// 5     a             Mode indicator, as in GNS
+/// 6-8   x.x           East, north and up projections of the baseline, meters
+/// 9     x.x           Baseline length, meters
+/// 10    x.x           Baseline course, degrees from true north
+/// 11-15               Reserved
+///
+/// $PSTI,036,hhmmss.sss,ddmmyy,x.x,x.x,x.x,a*hh
+/// 1     036           Message ID, heading, pitch and roll
+/// 2     hhmmss.sss    UTC time
+/// 3     ddmmyy        UTC date
+/// 4     x.x           Heading, degrees from true north
+/// 5     x.x           Pitch, degrees
+/// 6     x.x           Roll, degrees
+/// 7     a             Mode indicator, as in GNS
+pub fn parse_psti(sentence: NmeaSentence) -> Result<PstiData, NmeaError> {
+    if sentence.manufacturer != Some(b"STI") {
+        return Err(NmeaError::WrongSentenceHeader {
+            expected: b"STI",
+            found: sentence.manufacturer.unwrap_or(sentence.talker_id),
+        });
+    }
+    let (i, message_id) = take(3usize)(sentence.data)?;
+    let (i, _) = char(',')(i)?;
+    match message_id {
+        b"030" => Ok(PstiData::RtkPosition(do_parse_rtk_position(i)?.1)),
+        b"032" => Ok(PstiData::Baseline(do_parse_baseline(i)?.1)),
+        b"036" => Ok(PstiData::Attitude(do_parse_attitude(i)?.1)),
+        _ => Err(NmeaError::Unsupported(SentenceType::PSTI)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_psti_rtk_position() {
+        let s = parse_nmea_sentence(b"$PSTI,030,044606.000,A,2447.0924110,N,12100.5227860,E,103.323,0.00,0.00,0.00,180915,R,1.2,4.2*02").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = match parse_psti(s).unwrap() {
+            PstiData::RtkPosition(data) => data,
+            data => panic!("Unexpected data {:?}", data),
+        };
+        assert_eq!(data.fix_time, NaiveTime::from_hms_opt(4, 46, 6));
+        assert_eq!(data.fix_date, NaiveDate::from_ymd_opt(2015, 9, 18));
+        assert!(data.valid);
+        assert_relative_eq!(data.latitude.unwrap(), 24. + 47.092411 / 60.);
+        assert_relative_eq!(data.longitude.unwrap(), 121. + 0.522786 / 60.);
+        assert_relative_eq!(data.altitude.unwrap(), 103.323);
+        assert_eq!(data.east_velocity, Some(0.));
+        assert_eq!(data.mode, Some(GnsMode::RtkFixed));
+        assert_eq!(data.fix_type(), FixType::Rtk);
+        assert_relative_eq!(data.rtk_age.unwrap(), 1.2);
+        assert_relative_eq!(data.rtk_ratio.unwrap(), 4.2);
+
+        let s = parse_nmea_sentence(b"$PSTI,030,044607.000,V,,,,,,,,,180915,N,,*02").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        match parse_psti(s).unwrap() {
+            PstiData::RtkPosition(data) => {
+                assert!(!data.valid);
+                assert_eq!(data.latitude, None);
+                assert_eq!(data.rtk_age, None);
+                assert_eq!(data.fix_type(), FixType::Invalid);
+            }
+            data => panic!("Unexpected data {:?}", data),
+        }
+    }
+
+    #[test]
+    fn test_parse_psti_baseline() {
+        let s = parse_nmea_sentence(
+            b"$PSTI,032,041457.000,170316,A,R,0.603,-0.837,-0.089,1.036,144.22,,,,,*1C",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            parse_psti(s).unwrap(),
+            PstiData::Baseline(PstiBaselineData {
+                fix_time: NaiveTime::from_hms_opt(4, 14, 57),
+                fix_date: NaiveDate::from_ymd_opt(2016, 3, 17),
+                valid: true,
+                mode: Some(GnsMode::RtkFixed),
+                east: Some(0.603),
+                north: Some(-0.837),
+                up: Some(-0.089),
+                length: Some(1.036),
+                course: Some(144.22),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_psti_attitude() {
+        let s =
+            parse_nmea_sentence(b"$PSTI,036,054314.000,030521,191.69,-16.35,0.00,R*4D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = match parse_psti(s).unwrap() {
+            PstiData::Attitude(data) => data,
+            data => panic!("Unexpected data {:?}", data),
+        };
+        assert_eq!(data.heading, Some(191.69));
+        assert_eq!(data.pitch, Some(-16.35));
+        assert_eq!(data.roll, Some(0.));
+        assert!(data.is_valid());
+
+        let s = parse_nmea_sentence(b"$PSTI,036,054315.000,030521,,,,N*54").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        match parse_psti(s).unwrap() {
+            PstiData::Attitude(data) => assert!(!data.is_valid()),
+            data => panic!("Unexpected data {:?}", data),
+        }
+
+        let s = parse_nmea_sentence(b"$PSTI,004,1,2*05").unwrap();
+        assert_eq!(
+            parse_psti(s),
+            Err(NmeaError::Unsupported(SentenceType::PSTI))
+        );
+    }
+}
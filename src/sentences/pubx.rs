@@ -0,0 +1,462 @@
+use chrono::{NaiveDate, NaiveTime};
+use nom::bytes::complete::take;
+use nom::character::complete::{char, one_of};
+use nom::combinator::{opt, verify};
+use nom::multi::count;
+use nom::number::complete::{double, float};
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{number, parse_date, parse_hms, parse_lat_lon, speed_to_knots};
+use crate::{FixType, NmeaError, SentenceType};
+
+/// Navigation status of a u-blox receiver
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PubxNavStatus {
+    NoFix,
+    DeadReckoning,
+    Standalone2D,
+    Standalone3D,
+    Differential2D,
+    Differential3D,
+    /// Combined GNSS and dead reckoning solution
+    Combined,
+    TimeOnly,
+}
+
+impl From<PubxNavStatus> for FixType {
+    fn from(status: PubxNavStatus) -> Self {
+        match status {
+            PubxNavStatus::NoFix | PubxNavStatus::TimeOnly => FixType::Invalid,
+            PubxNavStatus::DeadReckoning => FixType::Estimated,
+            PubxNavStatus::Standalone2D | PubxNavStatus::Standalone3D | PubxNavStatus::Combined => {
+                FixType::Gps
+            }
+            PubxNavStatus::Differential2D | PubxNavStatus::Differential3D => FixType::DGps,
+        }
+    }
+}
+
+/// Position, PUBX,00
+///
+/// Accuracies are in meters, the speed over ground is normalised to knots.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PubxPositionData {
+    pub fix_time: NaiveTime,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Meters above the ellipsoid of the user datum, not above mean sea level
+    pub altitude: f32,
+    pub nav_status: PubxNavStatus,
+    pub horizontal_accuracy: f32,
+    pub vertical_accuracy: f32,
+    pub speed_over_ground: f32,
+    pub true_course: f32,
+    /// Meters per second, positive downwards
+    pub vertical_velocity: f32,
+    /// Age of the differential corrections, seconds
+    pub differential_age: Option<f32>,
+    pub hdop: f32,
+    pub vdop: f32,
+    pub tdop: f32,
+    pub fix_satellites: u32,
+    pub dead_reckoning: bool,
+}
+
+impl PubxPositionData {
+    pub fn fix_type(&self) -> FixType {
+        self.nav_status.into()
+    }
+}
+
+/// Use of a satellite in the navigation solution
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PubxSatelliteStatus {
+    NotUsed,
+    Used,
+    /// Ephemeris available, but not used for navigation
+    EphemerisAvailable,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PubxSatellite {
+    pub prn: u32,
+    pub status: PubxSatelliteStatus,
+    pub azimuth: Option<f32>,
+    pub elevation: Option<f32>,
+    /// Carrier to noise ratio, dBHz
+    pub snr: Option<f32>,
+    /// Seconds the carrier has been locked, up to 64
+    pub lock_time: Option<u8>,
+}
+
+/// Satellite status, PUBX,03
+#[derive(Debug, PartialEq, Clone)]
+pub struct PubxSatellitesData {
+    pub satellites: Vec<PubxSatellite>,
+}
+
+/// Time of day and clock information, PUBX,04
+#[derive(Debug, PartialEq, Clone)]
+pub struct PubxTimeData {
+    pub fix_time: NaiveTime,
+    pub fix_date: NaiveDate,
+    /// UTC time of week, seconds
+    pub time_of_week: f64,
+    pub week: u16,
+    pub leap_seconds: u8,
+    /// True when the leap seconds are the firmware default, not yet decoded
+    /// from the satellite data
+    pub leap_seconds_default: bool,
+    /// Receiver clock bias, nanoseconds
+    pub clock_bias: i64,
+    /// Receiver clock drift, nanoseconds per second
+    pub clock_drift: f32,
+    /// Time pulse granularity, nanoseconds
+    pub time_pulse_granularity: u32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PubxData {
+    Position(PubxPositionData),
+    Satellites(PubxSatellitesData),
+    Time(PubxTimeData),
+}
+
+fn parse_nav_status(i: &[u8]) -> IResult<&[u8], PubxNavStatus> {
+    let (rest, status) = take(2usize)(i)?;
+    let status = match status {
+        b"NF" => PubxNavStatus::NoFix,
+        b"DR" => PubxNavStatus::DeadReckoning,
+        b"G2" => PubxNavStatus::Standalone2D,
+        b"G3" => PubxNavStatus::Standalone3D,
+        b"D2" => PubxNavStatus::Differential2D,
+        b"D3" => PubxNavStatus::Differential3D,
+        b"RK" => PubxNavStatus::Combined,
+        b"TT" => PubxNavStatus::TimeOnly,
+        _ => return Err(nom::Err::Error((i, nom::error::ErrorKind::OneOf))),
+    };
+    Ok((rest, status))
+}
+
+fn do_parse_position(i: &[u8]) -> IResult<&[u8], PubxPositionData> {
+    // 1. UTC time
+    let (i, fix_time) = parse_hms(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. - 5. Latitude, N/S, longitude, E/W
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Altitude above user datum ellipsoid
+    let (i, altitude) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Navigation status
+    let (i, nav_status) = parse_nav_status(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Horizontal accuracy estimate
+    let (i, horizontal_accuracy) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Vertical accuracy estimate
+    let (i, vertical_accuracy) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    // 10. Speed over ground, km/h
+    let (i, speed_over_ground) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. Course over ground
+    let (i, true_course) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    // 12. Vertical velocity, positive downwards
+    let (i, vertical_velocity) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    // 13. Age of differential corrections
+    let (i, differential_age) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 14. - 16. HDOP, VDOP, TDOP
+    let (i, hdop) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, vdop) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, tdop) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    // 17. Number of satellites used in the navigation solution
+    let (i, fix_satellites) = number::<u32>(i)?;
+    let (i, _) = char(',')(i)?;
+    // 18. Reserved
+    let (i, _) = opt(number::<u32>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 19. Dead reckoning used
+    let (i, dead_reckoning) = one_of("01")(i)?;
+
+    Ok((
+        i,
+        PubxPositionData {
+            fix_time,
+            latitude: lat_lon.map(|v| v.0),
+            longitude: lat_lon.map(|v| v.1),
+            altitude,
+            nav_status,
+            horizontal_accuracy,
+            vertical_accuracy,
+            speed_over_ground: speed_to_knots(speed_over_ground, 'K'),
+            true_course,
+            vertical_velocity,
+            differential_age,
+            hdop,
+            vdop,
+            tdop,
+            fix_satellites,
+            dead_reckoning: dead_reckoning == '1',
+        },
+    ))
+}
+
+fn parse_satellite(i: &[u8]) -> IResult<&[u8], PubxSatellite> {
+    let (i, prn) = number::<u32>(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, status) = one_of("-Ue")(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, azimuth) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, elevation) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, snr) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lock_time) = opt(number::<u8>)(i)?;
+    Ok((
+        i,
+        PubxSatellite {
+            prn,
+            status: match status {
+                'U' => PubxSatelliteStatus::Used,
+                'e' => PubxSatelliteStatus::EphemerisAvailable,
+                _ => PubxSatelliteStatus::NotUsed,
+            },
+            azimuth,
+            elevation,
+            snr,
+            lock_time,
+        },
+    ))
+}
+
+fn do_parse_satellites(i: &[u8]) -> IResult<&[u8], PubxSatellitesData> {
+    // 1. Number of satellites
+    let (i, num_satellites) = number::<usize>(i)?;
+    // 2. - n. Satellite ID, status, azimuth, elevation, C/No and lock time
+    let (i, satellites) = count(preceded(char(','), parse_satellite), num_satellites)(i)?;
+    Ok((i, PubxSatellitesData { satellites }))
+}
+
+fn do_parse_time(i: &[u8]) -> IResult<&[u8], PubxTimeData> {
+    // 1. UTC time
+    let (i, fix_time) = parse_hms(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. UTC date, ddmmyy
+    let (i, fix_date) = parse_date(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. UTC time of week
+    let (i, time_of_week) = double(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. UTC week number
+    let (i, week) = number::<u16>(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Leap seconds, followed by a D if it is the firmware default
+    let (i, leap_seconds) = number::<u8>(i)?;
+    let (i, leap_seconds_default) = opt(char('D'))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Receiver clock bias, ns
+    let (i, clock_bias) = verify(double, |bias| bias.fract() == 0.)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Receiver clock drift, ns/s
+    let (i, clock_drift) = float(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Time pulse granularity, ns
+    let (i, time_pulse_granularity) = number::<u32>(i)?;
+
+    Ok((
+        i,
+        PubxTimeData {
+            fix_time,
+            fix_date,
+            time_of_week,
+            week,
+            leap_seconds,
+            leap_seconds_default: leap_seconds_default.is_some(),
+            clock_bias: clock_bias as i64,
+            clock_drift,
+            time_pulse_granularity,
+        },
+    ))
+}
+
+/// Parse u-blox PUBX message
+/// from u-blox protocol specification:
+/// $PUBX,00,hhmmss.ss,ddmm.mmmmm,c,dddmm.mmmmm,c,x.x,cc,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x,x,x*hh
+/// 1     00            Message ID, position
+/// 2     hhmmss.ss     UTC time
+/// 3,4   ddmm.mmmmm,c  Latitude, N/S
+/// 5,6   dddmm.mmmmm,c Longitude, E/W
+/// 7     x.x           Altitude above user datum ellipsoid, meters
+/// 8     cc            Navigation status: NF = No fix, DR = Dead reckoning only,
+///                     G2 = Stand alone 2D, G3 = Stand alone 3D, D2 = Differential 2D,
+///                     D3 = Differential 3D, RK = Combined GNSS and dead reckoning,
+///                     TT = Time only
+/// 9     x.x           Horizontal accuracy estimate, meters
+/// 10    x.x           Vertical accuracy estimate, meters
+/// 11    x.x           Speed over ground, km/h
+/// 12    x.x           Course over ground, degrees
+/// 13    x.x           Vertical velocity, m/s, positive downwards
+/// 14    x.x           Age of differential corrections, seconds
+/// 15-17 x.x           HDOP, VDOP, TDOP
+/// 18    x             Number of satellites used in the navigation solution
+/// 19    x             Reserved
+/// 20    x             Dead reckoning used
+///
+/// $PUBX,03,xx{,xx,c,xxx,xx,xx,xx}*hh
+/// 1     03            Message ID, satellite status
+/// 2     xx            Number of satellites, followed by six fields for each of them:
+///                     satellite ID, status (- = not used, U = used,
+///                     e = ephemeris available but not used), azimuth, elevation,
+///                     carrier to noise ratio in dBHz and carrier lock time in seconds
+///
+/// $PUBX,04,hhmmss.ss,ddmmyy,x.x,xxxx,xx[D],x,x.x,x,*hh
+/// 1     04            Message ID, time of day and clock information
+/// 2     hhmmss.ss     UTC time
+/// 3     ddmmyy        UTC date
+/// 4     x.x           UTC time of week, seconds
+/// 5     xxxx          UTC week number
+/// 6     xx[D]         Leap seconds, D marks the firmware default value
+/// 7     x             Receiver clock bias, ns
+/// 8     x.x           Receiver clock drift, ns/s
+/// 9     x             Time pulse granularity, ns
+pub fn parse_pubx(sentence: NmeaSentence) -> Result<PubxData, NmeaError> {
+    if sentence.manufacturer != Some(b"UBX") {
+        return Err(NmeaError::WrongSentenceHeader {
+            expected: b"UBX",
+            found: sentence.manufacturer.unwrap_or(sentence.talker_id),
+        });
+    }
+    let (i, message_id) = take(2usize)(sentence.data)?;
+    let (i, _) = char(',')(i)?;
+    match message_id {
+        b"00" => Ok(PubxData::Position(do_parse_position(i)?.1)),
+        b"03" => Ok(PubxData::Satellites(do_parse_satellites(i)?.1)),
+        b"04" => Ok(PubxData::Time(do_parse_time(i)?.1)),
+        _ => Err(NmeaError::Unsupported(SentenceType::PUBX)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_pubx_position() {
+        let s = parse_nmea_sentence(b"$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = match parse_pubx(s).unwrap() {
+            PubxData::Position(data) => data,
+            data => panic!("Unexpected data {:?}", data),
+        };
+        assert_eq!(data.fix_time, NaiveTime::from_hms_opt(8, 13, 50).unwrap());
+        assert_relative_eq!(data.latitude.unwrap(), 47. + 17.113210 / 60.);
+        assert_relative_eq!(data.longitude.unwrap(), 8. + 33.915187 / 60.);
+        assert_relative_eq!(data.altitude, 546.589);
+        assert_eq!(data.nav_status, PubxNavStatus::Standalone3D);
+        assert_eq!(data.fix_type(), FixType::Gps);
+        assert_relative_eq!(data.horizontal_accuracy, 2.1);
+        assert_relative_eq!(data.vertical_accuracy, 2.0);
+        assert_relative_eq!(data.speed_over_ground, 0.007 / 1.852);
+        assert_relative_eq!(data.true_course, 77.52);
+        assert_relative_eq!(data.vertical_velocity, 0.007);
+        assert_eq!(data.differential_age, None);
+        assert_relative_eq!(data.hdop, 0.92);
+        assert_relative_eq!(data.vdop, 1.19);
+        assert_relative_eq!(data.tdop, 0.77);
+        assert_eq!(data.fix_satellites, 9);
+        assert!(!data.dead_reckoning);
+
+        let s = parse_nmea_sentence(b"$PUBX,00,000114.00,0000.00000,N,00000.00000,E,0.000,NF,5303302,3750001,0.000,0.00,0.000,,99.99,99.99,99.99,0,0,0*2C").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        match parse_pubx(s).unwrap() {
+            PubxData::Position(data) => assert_eq!(data.fix_type(), FixType::Invalid),
+            data => panic!("Unexpected data {:?}", data),
+        }
+    }
+
+    #[test]
+    fn test_parse_pubx_satellites() {
+        let s = parse_nmea_sentence(b"$PUBX,03,11,23,-,,,45,010,29,-,,,46,013,07,-,,,42,015,08,U,067,31,42,025,10,U,195,33,46,026,18,U,326,08,39,026,17,-,,,32,015,26,U,306,66,48,025,27,U,073,10,36,026,28,U,089,61,46,024,15,-,,,39,014*0D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = match parse_pubx(s).unwrap() {
+            PubxData::Satellites(data) => data,
+            data => panic!("Unexpected data {:?}", data),
+        };
+        assert_eq!(data.satellites.len(), 11);
+        assert_eq!(
+            data.satellites[0],
+            PubxSatellite {
+                prn: 23,
+                status: PubxSatelliteStatus::NotUsed,
+                azimuth: None,
+                elevation: None,
+                snr: Some(45.),
+                lock_time: Some(10),
+            }
+        );
+        assert_eq!(
+            data.satellites[3],
+            PubxSatellite {
+                prn: 8,
+                status: PubxSatelliteStatus::Used,
+                azimuth: Some(67.),
+                elevation: Some(31.),
+                snr: Some(42.),
+                lock_time: Some(25),
+            }
+        );
+        assert_eq!(
+            data.satellites
+                .iter()
+                .filter(|sat| sat.status == PubxSatelliteStatus::Used)
+                .count(),
+            6
+        );
+    }
+
+    #[test]
+    fn test_parse_pubx_time() {
+        let s = parse_nmea_sentence(
+            b"$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            parse_pubx(s).unwrap(),
+            PubxData::Time(PubxTimeData {
+                fix_time: NaiveTime::from_hms_opt(7, 37, 31).unwrap(),
+                fix_date: NaiveDate::from_ymd_opt(2002, 12, 9).unwrap(),
+                time_of_week: 113851.,
+                week: 1196,
+                leap_seconds: 15,
+                leap_seconds_default: true,
+                clock_bias: 1930035,
+                clock_drift: -2660.664,
+                time_pulse_granularity: 43,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_pubx_unsupported() {
+        let s = parse_nmea_sentence(b"$PUBX,40,GLL,1,0,0,0,0,0*5D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            parse_pubx(s),
+            Err(NmeaError::Unsupported(SentenceType::PUBX))
+        );
+    }
+}
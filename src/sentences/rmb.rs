@@ -0,0 +1,173 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_lat_lon, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+/// Direction to steer to get back on track
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SteerDirection {
+    Left,
+    Right,
+}
+
+pub(crate) fn parse_steer_direction(i: &[u8]) -> IResult<&[u8], Option<SteerDirection>> {
+    let (i, direction) = opt(one_of("LR"))(i)?;
+    Ok((
+        i,
+        direction.map(|c| match c {
+            'L' => SteerDirection::Left,
+            'R' => SteerDirection::Right,
+            _ => unreachable!(),
+        }),
+    ))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RmbData {
+    pub valid: bool,
+    pub cross_track_error: Option<f32>,
+    pub steer_direction: Option<SteerDirection>,
+    pub origin_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub destination_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub destination_latitude: Option<f64>,
+    pub destination_longitude: Option<f64>,
+    pub range_to_destination: Option<f32>,
+    pub bearing_to_destination: Option<f32>,
+    pub closing_velocity: Option<f32>,
+    pub arrived: bool,
+}
+
+fn do_parse_rmb(i: &[u8]) -> Result<RmbData, NmeaError<'_>> {
+    // 1. Status, A = Active, V = Void
+    let (i, status) = one_of("AV")(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 2. Cross Track error - nautical miles
+    let (i, cross_track_error) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Direction to Steer, Left or Right
+    let (i, steer_direction) = parse_steer_direction(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 4. Origin Waypoint ID
+    let (i, origin_waypoint_id) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Destination Waypoint ID
+    let (i, destination_waypoint_id) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 6. Destination Waypoint Latitude
+    // 7. N or S
+    // 8. Destination Waypoint Longitude
+    // 9. E or W
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 10. Range to destination in nautical miles
+    let (i, range_to_destination) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. Bearing to destination in degrees True
+    let (i, bearing_to_destination) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 12. Destination closing velocity in knots
+    let (i, closing_velocity) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 13. Arrival Status, A = Arrival Circle Entered, V = not entered
+    let (_i, arrival_status) = opt(one_of("AV"))(i)?;
+
+    // 14. FAA mode indicator (NMEA 2.3 and later, optional)
+
+    Ok(RmbData {
+        valid: status == 'A',
+        cross_track_error,
+        steer_direction,
+        origin_waypoint_id: array_string(origin_waypoint_id)?,
+        destination_waypoint_id: array_string(destination_waypoint_id)?,
+        destination_latitude: lat_lon.map(|v| v.0),
+        destination_longitude: lat_lon.map(|v| v.1),
+        range_to_destination,
+        bearing_to_destination,
+        closing_velocity,
+        arrived: arrival_status == Some('A'),
+    })
+}
+
+/// Parse RMB message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_rmb_recommended_minimum_navigation_information
+///
+/// $--RMB,A,x.x,a,c--c,c--c,llll.ll,a,yyyyy.yy,a,x.x,x.x,x.x,A,m*hh<CR><LF>
+pub fn parse_rmb(sentence: NmeaSentence) -> Result<RmbData, NmeaError> {
+    if sentence.message_id != b"RMB" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"RMB",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_rmb(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_rmb_full() {
+        let sentence = parse_nmea_sentence(
+            b"$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20",
+        )
+        .unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_rmb(sentence).unwrap();
+
+        assert!(data.valid);
+        assert_relative_eq!(data.cross_track_error.unwrap(), 0.66);
+        assert_eq!(data.steer_direction, Some(SteerDirection::Left));
+        assert_eq!(&data.origin_waypoint_id.unwrap(), "003");
+        assert_eq!(&data.destination_waypoint_id.unwrap(), "004");
+        assert_relative_eq!(data.destination_latitude.unwrap(), 49. + 17.24 / 60.);
+        assert_relative_eq!(data.destination_longitude.unwrap(), -(123. + 9.57 / 60.));
+        assert_relative_eq!(data.range_to_destination.unwrap(), 1.3);
+        assert_relative_eq!(data.bearing_to_destination.unwrap(), 52.5);
+        assert_relative_eq!(data.closing_velocity.unwrap(), 0.5);
+        assert!(!data.arrived);
+    }
+
+    #[test]
+    fn test_parse_rmb_with_optional_fields() {
+        let sentence = parse_nmea_sentence(b"$GPRMB,V,,,,,,,,,,,,A,N*13").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_rmb(sentence).unwrap();
+
+        assert_eq!(
+            RmbData {
+                valid: false,
+                cross_track_error: None,
+                steer_direction: None,
+                origin_waypoint_id: None,
+                destination_waypoint_id: None,
+                destination_latitude: None,
+                destination_longitude: None,
+                range_to_destination: None,
+                bearing_to_destination: None,
+                closing_velocity: None,
+                arrived: true,
+            },
+            data
+        );
+    }
+}
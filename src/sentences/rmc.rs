@@ -108,9 +108,12 @@ mod tests {
         let rmc_data = parse_rmc(s).unwrap();
         assert_eq!(
             rmc_data.fix_time.unwrap(),
-            NaiveTime::from_hms_milli(22, 54, 46, 330)
+            NaiveTime::from_hms_milli_opt(22, 54, 46, 330).unwrap()
+        );
+        assert_eq!(
+            rmc_data.fix_date.unwrap(),
+            NaiveDate::from_ymd_opt(1994, 11, 19).unwrap()
         );
-        assert_eq!(rmc_data.fix_date.unwrap(), NaiveDate::from_ymd(1994, 11, 19));
 
         println!("lat: {}", rmc_data.lat.unwrap());
         relative_eq!(rmc_data.lat.unwrap(), 49.0 + 16.45 / 60.);
@@ -0,0 +1,71 @@
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct RotData {
+    /// Rate of turn, degrees per minute, negative means the bow turns to port
+    pub rate_of_turn: Option<f32>,
+    pub valid: bool,
+}
+
+fn do_parse_rot(i: &[u8]) -> IResult<&[u8], RotData> {
+    let (i, rate_of_turn) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, status) = one_of("AV")(i)?;
+    Ok((
+        i,
+        RotData {
+            rate_of_turn,
+            valid: status == 'A',
+        },
+    ))
+}
+
+/// Parse ROT message
+/// from gpsd:
+/// $--ROT,x.x,A*hh
+/// 1     x.x   Rate Of Turn, degrees per minute, "-" means bow turns to port
+/// 2     A     Status, A = data valid, V = invalid
+pub fn parse_rot(sentence: NmeaSentence) -> Result<RotData, NmeaError> {
+    if sentence.message_id != b"ROT" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"ROT",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_rot(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_rot() {
+        let s = parse_nmea_sentence(b"$IIROT,-35.6,A*3B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            RotData {
+                rate_of_turn: Some(-35.6),
+                valid: true,
+            },
+            parse_rot(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$IIROT,,V*1F").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            RotData {
+                rate_of_turn: None,
+                valid: false,
+            },
+            parse_rot(s).unwrap()
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::number;
+use crate::NmeaError;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RpmSource {
+    Shaft,
+    Engine,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RpmData {
+    pub source: RpmSource,
+    /// Engine or shaft number, numbered from centerline, odd starboard and
+    /// even port
+    pub number: u8,
+    /// Revolutions per minute, negative means astern
+    pub speed: Option<f32>,
+    /// Propeller pitch, percent of the maximum, negative means astern
+    pub pitch: Option<f32>,
+    pub valid: bool,
+}
+
+fn do_parse_rpm(i: &[u8]) -> IResult<&[u8], RpmData> {
+    let (i, source) = one_of("SE")(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, number) = number::<u8>(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, pitch) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, status) = one_of("AV")(i)?;
+    Ok((
+        i,
+        RpmData {
+            source: match source {
+                'S' => RpmSource::Shaft,
+                'E' => RpmSource::Engine,
+                _ => unreachable!(),
+            },
+            number,
+            speed,
+            pitch,
+            valid: status == 'A',
+        },
+    ))
+}
+
+/// Parse RPM message
+/// from gpsd:
+/// $--RPM,a,x,x.x,x.x,A*hh
+/// 1     a     Source, S = Shaft, E = Engine
+/// 2     x     Engine or shaft number
+/// 3     x.x   Speed, Revolutions per minute
+/// 4     x.x   Propeller pitch, % of maximum, "-" means astern
+/// 5     A     Status, A = Data is valid
+pub fn parse_rpm(sentence: NmeaSentence) -> Result<RpmData, NmeaError> {
+    if sentence.message_id != b"RPM" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"RPM",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_rpm(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_rpm() {
+        let s = parse_nmea_sentence(b"$IIRPM,E,1,2418.2,10.5,A*5F").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            RpmData {
+                source: RpmSource::Engine,
+                number: 1,
+                speed: Some(2418.2),
+                pitch: Some(10.5),
+                valid: true,
+            },
+            parse_rpm(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$IIRPM,S,2,-850.0,,A*4D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let rpm = parse_rpm(s).unwrap();
+        assert_eq!(rpm.source, RpmSource::Shaft);
+        assert_eq!(rpm.speed, Some(-850.));
+        assert_eq!(rpm.pitch, None);
+
+        let s = parse_nmea_sentence(b"$IIRPM,E,1,,,V*41").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert!(!parse_rpm(s).unwrap().valid);
+    }
+}
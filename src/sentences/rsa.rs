@@ -0,0 +1,86 @@
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+/// Rudder angles in degrees, negative means the bow turns to port
+#[derive(Debug, PartialEq)]
+pub struct RsaData {
+    /// Starboard rudder, or the single rudder
+    pub starboard: Option<f32>,
+    pub starboard_valid: bool,
+    pub port: Option<f32>,
+    pub port_valid: bool,
+}
+
+fn do_parse_rsa(i: &[u8]) -> IResult<&[u8], RsaData> {
+    let (i, starboard) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, starboard_status) = opt(one_of("AV"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, port) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, port_status) = opt(one_of("AV"))(i)?;
+    Ok((
+        i,
+        RsaData {
+            starboard,
+            starboard_valid: starboard_status == Some('A'),
+            port,
+            port_valid: port_status == Some('A'),
+        },
+    ))
+}
+
+/// Parse RSA message
+/// from gpsd:
+/// $--RSA,x.x,A,x.x,A*hh
+/// 1     x.x   Starboard (or single) rudder sensor, "-" means Turn To Port
+/// 2     A     Status, A = valid, V = Invalid
+/// 3     x.x   Port rudder sensor
+/// 4     A     Status, A = valid, V = Invalid
+pub fn parse_rsa(sentence: NmeaSentence) -> Result<RsaData, NmeaError> {
+    if sentence.message_id != b"RSA" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"RSA",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_rsa(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_rsa() {
+        let s = parse_nmea_sentence(b"$IIRSA,10.5,A,,V*4D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            RsaData {
+                starboard: Some(10.5),
+                starboard_valid: true,
+                port: None,
+                port_valid: false,
+            },
+            parse_rsa(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$IIRSA,-5.2,A,-4.8,A*4B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            RsaData {
+                starboard: Some(-5.2),
+                starboard_valid: true,
+                port: Some(-4.8),
+                port_valid: true,
+            },
+            parse_rsa(s).unwrap()
+        );
+    }
+}
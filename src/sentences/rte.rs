@@ -0,0 +1,128 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, one_of};
+use nom::combinator::verify;
+use nom::multi::many0;
+use nom::sequence::preceded;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, number, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RteMode {
+    /// Complete list of waypoints of the route
+    Complete,
+    /// Working route, the first waypoint is the one from which the ship is
+    /// departing and the second is the destination
+    Working,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RteData {
+    pub total_sentences: u16,
+    pub sentence_num: u16,
+    pub mode: RteMode,
+    pub route_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub waypoint_ids: Vec<ArrayString<[u8; MAX_LEN]>>,
+}
+
+fn do_parse_rte(i: &[u8]) -> Result<RteData, NmeaError<'_>> {
+    // 1. Total number of sentences being transmitted
+    let (i, total_sentences) = number::<u16>(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Sentence number
+    let (i, sentence_num) = verify(number::<u16>, |num| (1..=total_sentences).contains(num))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Mode, c = complete route, w = working route
+    let (i, mode) = one_of("cwCW")(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Route ID
+    let (i, route_id) = parse_str_field(i)?;
+    // 5. - n. Waypoint IDs
+    let (_i, waypoint_ids) = many0(preceded(char(','), parse_str_field))(i)?;
+
+    Ok(RteData {
+        total_sentences,
+        sentence_num,
+        mode: match mode {
+            'c' | 'C' => RteMode::Complete,
+            'w' | 'W' => RteMode::Working,
+            _ => unreachable!(),
+        },
+        route_id: array_string(route_id)?,
+        waypoint_ids: waypoint_ids
+            .into_iter()
+            .filter_map(|id| array_string(id).transpose())
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Parse RTE message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_rte_routes
+///
+/// $--RTE,x.x,x.x,a,c--c,c--c, ..... c--c*hh<CR><LF>
+///
+/// A route may be split over several sentences, they have to be
+/// combined in the order of their sentence number.
+pub fn parse_rte(sentence: NmeaSentence) -> Result<RteData, NmeaError> {
+    if sentence.message_id != b"RTE" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"RTE",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_rte(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_rte() {
+        let sentence = parse_nmea_sentence(
+            b"$GPRTE,2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND,PYAMBU,PPFAIR,PWARRN,PMORTL,PLISMR*73",
+        )
+        .unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_rte(sentence).unwrap();
+
+        assert_eq!(data.total_sentences, 2);
+        assert_eq!(data.sentence_num, 1);
+        assert_eq!(data.mode, RteMode::Complete);
+        assert_eq!(&data.route_id.unwrap(), "0");
+        let ids: Vec<&str> = data.waypoint_ids.iter().map(|id| id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "PBRCPK", "PBRTO", "PTELGR", "PPLAND", "PYAMBU", "PPFAIR", "PWARRN", "PMORTL",
+                "PLISMR"
+            ]
+        );
+
+        let sentence = parse_nmea_sentence(b"$GPRTE,1,1,w,MYROUTE,003,004*69").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_rte(sentence).unwrap();
+
+        assert_eq!(data.mode, RteMode::Working);
+        assert_eq!(&data.route_id.unwrap(), "MYROUTE");
+        assert_eq!(data.waypoint_ids.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_rte_invalid_sentence_num() {
+        let sentence = parse_nmea_sentence(b"$GPRTE,1,2,w,MYROUTE,003,004*6A").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+        assert!(matches!(
+            parse_rte(sentence),
+            Err(NmeaError::ParsingError(_))
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+use arrayvec::ArrayString;
+use chrono::NaiveTime;
+use nom::character::complete::char;
+use nom::combinator::opt;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::ttm::{parse_target_status, TargetStatus};
+use crate::sentences::utils::{array_string, number, parse_hms, parse_lat_lon, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TllData {
+    pub target_number: u8,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub name: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub time: Option<NaiveTime>,
+    pub status: Option<TargetStatus>,
+    pub reference_target: bool,
+}
+
+fn do_parse_tll(i: &[u8]) -> Result<TllData, NmeaError<'_>> {
+    // 1. Target number
+    let (i, target_number) = number::<u8>(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Target latitude
+    // 3. N or S
+    // 4. Target longitude
+    // 5. E or W
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Target name
+    let (i, name) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. UTC of data
+    let (i, time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Target status
+    let (i, status) = parse_target_status(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Reference target, R or null
+    let (_i, reference_target) = opt(char('R'))(i)?;
+
+    Ok(TllData {
+        target_number,
+        latitude: lat_lon.map(|v| v.0),
+        longitude: lat_lon.map(|v| v.1),
+        name: array_string(name)?,
+        time,
+        status,
+        reference_target: reference_target.is_some(),
+    })
+}
+
+/// Parse TLL message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_tll_target_latitude_and_longitude
+///
+/// $--TLL,xx,llll.lll,a,yyyyy.yyy,a,c--c,hhmmss.ss,a,a*hh<CR><LF>
+pub fn parse_tll(sentence: NmeaSentence) -> Result<TllData, NmeaError> {
+    if sentence.message_id != b"TLL" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"TLL",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_tll(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_tll() {
+        let sentence =
+            parse_nmea_sentence(b"$RATLL,01,4917.24,N,12309.57,W,SHIP1,100524.00,T,*0E").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_tll(sentence).unwrap();
+
+        assert_eq!(data.target_number, 1);
+        assert_relative_eq!(data.latitude.unwrap(), 49. + 17.24 / 60.);
+        assert_relative_eq!(data.longitude.unwrap(), -(123. + 9.57 / 60.));
+        assert_eq!(&data.name.unwrap(), "SHIP1");
+        assert_eq!(data.time, NaiveTime::from_hms_opt(10, 5, 24));
+        assert_eq!(data.status, Some(TargetStatus::Tracking));
+        assert!(!data.reference_target);
+    }
+
+    #[test]
+    fn test_parse_tll_empty() {
+        let sentence = parse_nmea_sentence(b"$RATLL,03,,,,,,,Q,R*6B").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            TllData {
+                target_number: 3,
+                latitude: None,
+                longitude: None,
+                name: None,
+                time: None,
+                status: Some(TargetStatus::Query),
+                reference_target: true,
+            },
+            parse_tll(sentence).unwrap()
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use arrayvec::ArrayString;
+use chrono::NaiveTime;
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, number, parse_hms, parse_str_field, speed_to_knots};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+/// Whether a bearing or course is relative to true north or to the own ship
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TargetReference {
+    True,
+    Relative,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TargetStatus {
+    Lost,
+    /// Target is being acquired
+    Query,
+    Tracking,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TargetAcquisition {
+    Automatic,
+    Manual,
+    Reported,
+}
+
+pub(crate) fn parse_target_status(i: &[u8]) -> IResult<&[u8], Option<TargetStatus>> {
+    let (i, status) = opt(one_of("LQT"))(i)?;
+    Ok((
+        i,
+        status.map(|status| match status {
+            'L' => TargetStatus::Lost,
+            'Q' => TargetStatus::Query,
+            'T' => TargetStatus::Tracking,
+            _ => unreachable!(),
+        }),
+    ))
+}
+
+fn parse_reference(i: &[u8]) -> IResult<&[u8], Option<TargetReference>> {
+    let (i, reference) = opt(one_of("TR"))(i)?;
+    Ok((
+        i,
+        reference.map(|reference| match reference {
+            'T' => TargetReference::True,
+            'R' => TargetReference::Relative,
+            _ => unreachable!(),
+        }),
+    ))
+}
+
+/// Tracked target, distances are in nautical miles and speeds in knots
+#[derive(Debug, PartialEq, Clone)]
+pub struct TtmData {
+    pub target_number: u8,
+    pub distance: Option<f32>,
+    pub bearing: Option<f32>,
+    pub bearing_reference: Option<TargetReference>,
+    pub speed: Option<f32>,
+    pub course: Option<f32>,
+    pub course_reference: Option<TargetReference>,
+    pub cpa_distance: Option<f32>,
+    /// Time to CPA in minutes, negative when the CPA has been passed
+    pub time_to_cpa: Option<f32>,
+    pub name: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub status: Option<TargetStatus>,
+    pub reference_target: bool,
+    pub time: Option<NaiveTime>,
+    pub acquisition: Option<TargetAcquisition>,
+}
+
+fn do_parse_ttm(i: &[u8]) -> Result<TtmData, NmeaError<'_>> {
+    // 1. Target number
+    let (i, target_number) = number::<u8>(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Target distance
+    let (i, distance) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Bearing from own ship
+    // 4. T = True, R = Relative
+    let (i, bearing) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, bearing_reference) = parse_reference(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Target speed
+    let (i, speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Target course
+    // 7. T = True, R = Relative
+    let (i, course) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, course_reference) = parse_reference(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Distance of closest point of approach
+    let (i, cpa_distance) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Time until closest point of approach
+    let (i, time_to_cpa) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 10. Speed and distance units, K = km and km/h, N = nm and knots,
+    //     S = statute miles and mph
+    let (i, units) = opt(one_of("KNS"))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. Target name
+    let (i, name) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    // 12. Target status
+    let (i, status) = parse_target_status(i)?;
+    let (i, _) = char(',')(i)?;
+    // 13. Reference target, R or null
+    let (i, reference_target) = opt(char('R'))(i)?;
+    // 14. Time of data (NMEA 3.0 and later)
+    let (i, time) = opt(preceded(char(','), opt(parse_hms)))(i)?;
+    // 15. Type of acquisition (NMEA 3.0 and later)
+    let (_i, acquisition) = opt(preceded(char(','), opt(one_of("AMR"))))(i)?;
+
+    // Statute miles and kilometers convert to nautical miles with the same
+    // factors as their speeds to knots
+    let units = units.unwrap_or('N');
+    let to_nautical = |value: Option<f32>| value.map(|value| speed_to_knots(value, units));
+    Ok(TtmData {
+        target_number,
+        distance: to_nautical(distance),
+        bearing,
+        bearing_reference,
+        speed: to_nautical(speed),
+        course,
+        course_reference,
+        cpa_distance: to_nautical(cpa_distance),
+        time_to_cpa,
+        name: array_string(name)?,
+        status,
+        reference_target: reference_target.is_some(),
+        time: time.flatten(),
+        acquisition: acquisition.flatten().map(|acquisition| match acquisition {
+            'A' => TargetAcquisition::Automatic,
+            'M' => TargetAcquisition::Manual,
+            'R' => TargetAcquisition::Reported,
+            _ => unreachable!(),
+        }),
+    })
+}
+
+/// Parse TTM message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_ttm_tracked_target_message
+///
+/// $--TTM,xx,x.x,x.x,a,x.x,x.x,a,x.x,x.x,a,c--c,a,a,hhmmss.ss,a*hh<CR><LF>
+///
+/// Distances are normalised to nautical miles and speeds to knots.
+pub fn parse_ttm(sentence: NmeaSentence) -> Result<TtmData, NmeaError> {
+    if sentence.message_id != b"TTM" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"TTM",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_ttm(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_ttm() {
+        let sentence = parse_nmea_sentence(
+            b"$RATTM,01,1.34,320.2,T,12.5,145.0,T,0.22,4.5,N,SHIP1,T,,100523.00,A*02",
+        )
+        .unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_ttm(sentence).unwrap();
+
+        assert_eq!(
+            TtmData {
+                target_number: 1,
+                distance: Some(1.34),
+                bearing: Some(320.2),
+                bearing_reference: Some(TargetReference::True),
+                speed: Some(12.5),
+                course: Some(145.),
+                course_reference: Some(TargetReference::True),
+                cpa_distance: Some(0.22),
+                time_to_cpa: Some(4.5),
+                name: Some(ArrayString::from("SHIP1").unwrap()),
+                status: Some(TargetStatus::Tracking),
+                reference_target: false,
+                time: Some(NaiveTime::from_hms_opt(10, 5, 23).unwrap()),
+                acquisition: Some(TargetAcquisition::Automatic),
+            },
+            data
+        );
+    }
+
+    #[test]
+    fn test_parse_ttm_kilometers() {
+        let sentence =
+            parse_nmea_sentence(b"$RATTM,02,1.852,090.0,R,18.52,270.0,R,,,K,,Q,*66").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_ttm(sentence).unwrap();
+
+        assert_relative_eq!(data.distance.unwrap(), 1.);
+        assert_eq!(data.bearing_reference, Some(TargetReference::Relative));
+        assert_relative_eq!(data.speed.unwrap(), 10.);
+        assert_eq!(data.cpa_distance, None);
+        assert_eq!(data.name, None);
+        assert_eq!(data.status, Some(TargetStatus::Query));
+        assert_eq!(data.time, None);
+        assert_eq!(data.acquisition, None);
+    }
+}
@@ -1,14 +1,17 @@
 use core::str;
 
-use chrono::{NaiveDate, NaiveTime};
+use arrayvec::{Array, ArrayString};
+use chrono::{Duration, NaiveDate, NaiveTime};
 use nom::branch::alt;
-use nom::bytes::complete::{tag, take, take_until};
-use nom::character::complete::{char, digit1, one_of};
-use nom::combinator::{map, map_parser, map_res};
+use nom::bytes::complete::{is_not, tag, take, take_until};
+use nom::character::complete::{char, digit1, hex_digit1, one_of};
+use nom::combinator::{map, map_parser, map_res, opt};
 use nom::number::complete::double;
 use nom::sequence::tuple;
 use nom::IResult;
 
+use crate::NmeaError;
+
 pub(crate) fn parse_hms(i: &[u8]) -> IResult<&[u8], NaiveTime> {
     map_res(
         tuple((
@@ -40,6 +43,31 @@ pub(crate) fn parse_hms(i: &[u8]) -> IResult<&[u8], NaiveTime> {
     )(i)
 }
 
+/// Parse a time interval in the hhmmss.ss format, hours may exceed a day
+pub(crate) fn parse_duration(i: &[u8]) -> IResult<&[u8], Duration> {
+    map_res(
+        tuple((
+            map_res(take(2usize), parse_num::<u32>),
+            map_res(take(2usize), parse_num::<u32>),
+            map_parser(take_until(","), double),
+        )),
+        |(hours, minutes, sec)| -> core::result::Result<Duration, &'static str> {
+            if sec.is_sign_negative() {
+                return Err("Invalid duration: second is negative");
+            }
+            if minutes >= 60 {
+                return Err("Invalid duration: min >= 60");
+            }
+            if sec >= 60. {
+                return Err("Invalid duration: sec >= 60");
+            }
+            Ok(Duration::hours(i64::from(hours))
+                + Duration::minutes(i64::from(minutes))
+                + Duration::milliseconds((sec * 1000.).round() as i64))
+        },
+    )(i)
+}
+
 pub fn do_parse_lat_lon(i: &[u8]) -> IResult<&[u8], (f64, f64)> {
     let (i, lat_deg) = map_res(take(2usize), parse_num::<u8>)(i)?;
     let (i, lat_min) = double(i)?;
@@ -114,6 +142,37 @@ pub(crate) fn number<T: str::FromStr>(i: &[u8]) -> IResult<&[u8], T> {
     map_res(digit1, parse_num)(i)
 }
 
+/// Parse a free text field, such as a waypoint ID, up to the next `,` or `*`
+pub(crate) fn parse_str_field(i: &[u8]) -> IResult<&[u8], Option<&str>> {
+    opt(map_res(is_not(",*"), str::from_utf8))(i)
+}
+
+/// Copy a parsed text field into a fixed capacity string
+pub(crate) fn array_string<'a, A: Array<Item = u8> + Copy>(
+    s: Option<&str>,
+) -> Result<Option<ArrayString<A>>, NmeaError<'a>> {
+    s.map(|s| ArrayString::from(s).map_err(|_e| NmeaError::SentenceLength(s.len())))
+        .transpose()
+}
+
+/// Convert a speed to knots, the unit is given as in NMEA unit fields:
+/// `N` knots, `M` meters per second, `K` kilometers per hour and `S` statute
+/// miles per hour
+pub(crate) fn speed_to_knots(speed: f32, unit: char) -> f32 {
+    match unit {
+        'M' => speed * 3600. / 1852.,
+        'K' => speed / 1.852,
+        'S' => speed * 1609.344 / 1852.,
+        _ => speed,
+    }
+}
+
+pub(crate) fn hex_number(i: &[u8]) -> IResult<&[u8], u8> {
+    map_res(hex_digit1, |data: &[u8]| {
+        u8::from_str_radix(unsafe { str::from_utf8_unchecked(data) }, 16)
+    })(i)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,21 +197,31 @@ mod tests {
         assert_eq!(time.hour(), 12);
         assert_eq!(time.minute(), 56);
         assert_eq!(time.second(), 19);
-        assert_eq!(time.nanosecond(), 5_00_000_000);
+        assert_eq!(time.nanosecond(), 500_000_000);
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        let (_, duration) = parse_duration(b"493015.5,").unwrap();
+        assert_eq!(
+            duration,
+            Duration::hours(49) + Duration::minutes(30) + Duration::milliseconds(15_500)
+        );
+        assert!(parse_duration(b"006000,").is_err());
     }
 
     #[test]
     fn test_parse_date() {
         let (_, date) = parse_date(b"180283").unwrap();
-        assert_eq!(date, NaiveDate::from_ymd(1983, 02, 18));
+        assert_eq!(date, NaiveDate::from_ymd_opt(1983, 2, 18).unwrap());
 
         let (_, date) = parse_date(b"180299").unwrap();
-        assert_eq!(date, NaiveDate::from_ymd(1999, 02, 18));
+        assert_eq!(date, NaiveDate::from_ymd_opt(1999, 2, 18).unwrap());
 
         let (_, date) = parse_date(b"311200").unwrap();
-        assert_eq!(date, NaiveDate::from_ymd(2000, 12, 31));
+        assert_eq!(date, NaiveDate::from_ymd_opt(2000, 12, 31).unwrap());
 
         let (_, date) = parse_date(b"311282").unwrap();
-        assert_eq!(date, NaiveDate::from_ymd(2082, 12, 31));
+        assert_eq!(date, NaiveDate::from_ymd_opt(2082, 12, 31).unwrap());
     }
 }
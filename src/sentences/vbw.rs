@@ -0,0 +1,135 @@
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::sequence::{preceded, tuple};
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+/// Speeds of the vessel along and across its keel line, knots.
+/// Longitudinal speed is positive ahead, transverse speed is positive to
+/// starboard.
+#[derive(Debug, PartialEq)]
+pub struct VbwData {
+    pub longitudinal_water_speed: Option<f32>,
+    pub transverse_water_speed: Option<f32>,
+    pub water_speed_valid: bool,
+    pub longitudinal_ground_speed: Option<f32>,
+    pub transverse_ground_speed: Option<f32>,
+    pub ground_speed_valid: bool,
+    pub stern_transverse_water_speed: Option<f32>,
+    pub stern_water_speed_valid: bool,
+    pub stern_transverse_ground_speed: Option<f32>,
+    pub stern_ground_speed_valid: bool,
+}
+
+fn parse_status(i: &[u8]) -> IResult<&[u8], bool> {
+    let (i, status) = opt(one_of("AV"))(i)?;
+    Ok((i, status == Some('A')))
+}
+
+fn parse_stern_speed(i: &[u8]) -> IResult<&[u8], (Option<f32>, bool)> {
+    let (i, speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, valid) = parse_status(i)?;
+    Ok((i, (speed, valid)))
+}
+
+fn do_parse_vbw(i: &[u8]) -> IResult<&[u8], VbwData> {
+    let (i, longitudinal_water_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, transverse_water_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, water_speed_valid) = parse_status(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, longitudinal_ground_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, transverse_ground_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, ground_speed_valid) = parse_status(i)?;
+    let (i, stern) = opt(tuple((
+        preceded(char(','), parse_stern_speed),
+        preceded(char(','), parse_stern_speed),
+    )))(i)?;
+    let (
+        (stern_transverse_water_speed, stern_water_speed_valid),
+        (stern_transverse_ground_speed, stern_ground_speed_valid),
+    ) = stern.unwrap_or(((None, false), (None, false)));
+    Ok((
+        i,
+        VbwData {
+            longitudinal_water_speed,
+            transverse_water_speed,
+            water_speed_valid,
+            longitudinal_ground_speed,
+            transverse_ground_speed,
+            ground_speed_valid,
+            stern_transverse_water_speed,
+            stern_water_speed_valid,
+            stern_transverse_ground_speed,
+            stern_ground_speed_valid,
+        },
+    ))
+}
+
+/// Parse VBW message
+/// from gpsd:
+/// $--VBW,x.x,x.x,A,x.x,x.x,A,x.x,A,x.x,A*hh
+/// 1     x.x   Longitudinal water speed, "-" means astern, knots
+/// 2     x.x   Transverse water speed, "-" means port, knots
+/// 3     A     Status: A = Data Valid
+/// 4     x.x   Longitudinal ground speed, "-" means astern, knots
+/// 5     x.x   Transverse ground speed, "-" means port, knots
+/// 6     A     Status: A = Data Valid
+/// 7     x.x   Stern traverse water speed, knots (NMEA 3.0 and later)
+/// 8     A     Status: A = Data Valid (NMEA 3.0 and later)
+/// 9     x.x   Stern traverse ground speed, knots (NMEA 3.0 and later)
+/// 10    A     Status: A = Data Valid (NMEA 3.0 and later)
+pub fn parse_vbw(sentence: NmeaSentence) -> Result<VbwData, NmeaError> {
+    if sentence.message_id != b"VBW" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"VBW",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vbw(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_vbw() {
+        let s = parse_nmea_sentence(b"$IIVBW,5.50,-0.20,A,5.30,0.10,A*6B").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            VbwData {
+                longitudinal_water_speed: Some(5.5),
+                transverse_water_speed: Some(-0.2),
+                water_speed_valid: true,
+                longitudinal_ground_speed: Some(5.3),
+                transverse_ground_speed: Some(0.1),
+                ground_speed_valid: true,
+                stern_transverse_water_speed: None,
+                stern_water_speed_valid: false,
+                stern_transverse_ground_speed: None,
+                stern_ground_speed_valid: false,
+            },
+            parse_vbw(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$IIVBW,5.50,-0.20,A,,,V,0.10,A,,V*73").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let vbw = parse_vbw(s).unwrap();
+        assert!(vbw.water_speed_valid);
+        assert_eq!(vbw.longitudinal_ground_speed, None);
+        assert!(!vbw.ground_speed_valid);
+        assert_eq!(vbw.stern_transverse_water_speed, Some(0.1));
+        assert!(vbw.stern_water_speed_valid);
+        assert_eq!(vbw.stern_transverse_ground_speed, None);
+        assert!(!vbw.stern_ground_speed_valid);
+    }
+}
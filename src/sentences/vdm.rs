@@ -0,0 +1,148 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, none_of, one_of};
+use nom::combinator::{opt, verify};
+
+use crate::parse::NmeaSentence;
+use crate::sentences::ais::{decode_ais_payload, AisMessage};
+use crate::sentences::utils::{array_string, number, parse_str_field};
+use crate::NmeaError;
+
+const MAX_PAYLOAD_LEN: usize = 96;
+
+/// One fragment of an AIS message, from a VDM or a VDO sentence
+#[derive(Debug, PartialEq, Clone)]
+pub struct VdmData {
+    /// True for VDO, the report of the own vessel
+    pub own_vessel: bool,
+    pub fragment_count: u8,
+    pub fragment_number: u8,
+    /// Sequential message ID, links the fragments of a multi sentence message
+    pub message_id: Option<u8>,
+    pub channel: Option<char>,
+    pub payload: ArrayString<[u8; MAX_PAYLOAD_LEN]>,
+    pub fill_bits: u8,
+}
+
+impl VdmData {
+    /// Decode the payload of a message that fits in a single sentence
+    pub fn decode(&self) -> Result<AisMessage, NmeaError<'static>> {
+        if self.fragment_count != 1 {
+            return Err(NmeaError::InvalidAisPayload);
+        }
+        decode_ais_payload(&self.payload, self.fill_bits)
+    }
+}
+
+fn do_parse_vdm(i: &[u8], own_vessel: bool) -> Result<VdmData, NmeaError<'_>> {
+    // 1. Count of fragments
+    let (i, fragment_count) = verify(number::<u8>, |count| (1..=9).contains(count))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Fragment number
+    let (i, fragment_number) = verify(number::<u8>, |num| (1..=fragment_count).contains(num))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Sequential message ID
+    let (i, message_id) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Radio channel
+    let (i, channel) = opt(none_of(",*"))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Armored payload
+    let (i, payload) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Number of fill bits
+    let (_i, fill_bits) = one_of("012345")(i)?;
+
+    Ok(VdmData {
+        own_vessel,
+        fragment_count,
+        fragment_number,
+        message_id,
+        channel,
+        payload: array_string(payload)?.unwrap_or_default(),
+        fill_bits: fill_bits as u8 - b'0',
+    })
+}
+
+/// Parse VDM message
+/// from gpsd:
+/// !--VDM,x,x,x,a,s--s,x*hh
+/// 1     x     Count of fragments in the currently accumulating message
+/// 2     x     Fragment number of this sentence, one-based
+/// 3     x     Sequential message ID for multi-sentence messages
+/// 4     a     Radio channel code, A or B
+/// 5     s--s  Data payload, six bit armored
+/// 6     x     Number of fill bits added to the payload
+pub fn parse_vdm(sentence: NmeaSentence) -> Result<VdmData, NmeaError> {
+    if sentence.message_id != b"VDM" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"VDM",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vdm(sentence.data, false)?)
+    }
+}
+
+/// Parse VDO message, same as VDM for the reports of the own vessel
+pub fn parse_vdo(sentence: NmeaSentence) -> Result<VdmData, NmeaError> {
+    if sentence.message_id != b"VDO" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"VDO",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vdm(sentence.data, true)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_vdm() {
+        let s = parse_nmea_sentence(b"!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.talker_id, b"AI");
+        let vdm = parse_vdm(s).unwrap();
+        assert_eq!(
+            VdmData {
+                own_vessel: false,
+                fragment_count: 1,
+                fragment_number: 1,
+                message_id: None,
+                channel: Some('B'),
+                payload: ArrayString::from("177KQJ5000G?tO`K>RA1wUbN0TKH").unwrap(),
+                fill_bits: 0,
+            },
+            vdm
+        );
+        match vdm.decode().unwrap() {
+            AisMessage::PositionReport(report) => assert_eq!(report.mmsi, 477553000),
+            message => panic!("Unexpected message {:?}", message),
+        }
+
+        let s = parse_nmea_sentence(b"!AIVDM,2,2,1,A,88888888880,2*25").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let vdm = parse_vdm(s).unwrap();
+        assert_eq!(vdm.fragment_count, 2);
+        assert_eq!(vdm.fragment_number, 2);
+        assert_eq!(vdm.message_id, Some(1));
+        assert_eq!(vdm.fill_bits, 2);
+        assert_eq!(vdm.decode(), Err(NmeaError::InvalidAisPayload));
+    }
+
+    #[test]
+    fn test_parse_vdo() {
+        let s = parse_nmea_sentence(b"!AIVDO,1,1,,,B52K>;h00Fc>jpUlNV@ikwpUoP06,0*0F").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let vdo = parse_vdo(s).unwrap();
+        assert!(vdo.own_vessel);
+        assert_eq!(vdo.channel, None);
+        assert!(matches!(
+            vdo.decode(),
+            Ok(AisMessage::ClassBPositionReport(_))
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::sentences::utils::speed_to_knots;
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct VhwData {
+    /// Heading, degrees True
+    pub heading_true: Option<f32>,
+    /// Heading, degrees Magnetic
+    pub heading_magnetic: Option<f32>,
+    /// Speed through water, knots
+    pub speed_through_water: Option<f32>,
+}
+
+fn do_parse_vhw(i: &[u8]) -> IResult<&[u8], VhwData> {
+    let (i, heading_true) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('T'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, heading_magnetic) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_knots) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_kph) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('K'))(i)?;
+    Ok((
+        i,
+        VhwData {
+            heading_true,
+            heading_magnetic,
+            speed_through_water: speed_knots
+                .or_else(|| speed_kph.map(|speed| speed_to_knots(speed, 'K'))),
+        },
+    ))
+}
+
+/// Parse VHW message
+/// from gpsd:
+/// $--VHW,x.x,T,x.x,M,x.x,N,x.x,K*hh
+/// 1     x.x   Heading, degrees True
+/// 2     T     T = True
+/// 3     x.x   Heading, degrees Magnetic
+/// 4     M     M = Magnetic
+/// 5     x.x   Speed of vessel relative to the water, knots
+/// 6     N     N = Knots
+/// 7     x.x   Speed of vessel relative to the water, km/hr
+/// 8     K     K = Kilometers
+///
+/// Speed is normalised to knots.
+pub fn parse_vhw(sentence: NmeaSentence) -> Result<VhwData, NmeaError> {
+    if sentence.message_id != b"VHW" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"VHW",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vhw(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_vhw() {
+        let s = parse_nmea_sentence(b"$IIVHW,245.1,T,245.1,M,000.01,N,000.01,K*55").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            VhwData {
+                heading_true: Some(245.1),
+                heading_magnetic: Some(245.1),
+                speed_through_water: Some(0.01),
+            },
+            parse_vhw(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$VWVHW,,T,,M,,N,10.2,K*49").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let vhw = parse_vhw(s).unwrap();
+        assert_eq!(vhw.heading_true, None);
+        assert_relative_eq!(vhw.speed_through_water.unwrap(), 5.507559);
+    }
+}
@@ -0,0 +1,98 @@
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::sequence::{preceded, tuple};
+use nom::IResult;
+
+use crate::{parse::NmeaSentence, NmeaError};
+
+/// Distances travelled by the vessel, nautical miles
+#[derive(Debug, PartialEq)]
+pub struct VlwData {
+    pub total_water_distance: Option<f32>,
+    pub trip_water_distance: Option<f32>,
+    pub total_ground_distance: Option<f32>,
+    pub trip_ground_distance: Option<f32>,
+}
+
+fn parse_distance(i: &[u8]) -> IResult<&[u8], Option<f32>> {
+    let (i, distance) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('N'))(i)?;
+    Ok((i, distance))
+}
+
+fn do_parse_vlw(i: &[u8]) -> IResult<&[u8], VlwData> {
+    let (i, total_water_distance) = parse_distance(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, trip_water_distance) = parse_distance(i)?;
+    let (i, ground) = opt(tuple((
+        preceded(char(','), parse_distance),
+        preceded(char(','), parse_distance),
+    )))(i)?;
+    let (total_ground_distance, trip_ground_distance) = ground.unwrap_or((None, None));
+    Ok((
+        i,
+        VlwData {
+            total_water_distance,
+            trip_water_distance,
+            total_ground_distance,
+            trip_ground_distance,
+        },
+    ))
+}
+
+/// Parse VLW message
+/// from gpsd:
+/// $--VLW,x.x,N,x.x,N,x.x,N,x.x,N*hh
+/// 1     x.x   Total cumulative water distance, nm
+/// 2     N     N = Nautical Miles
+/// 3     x.x   Water distance since Reset, nm
+/// 4     N     N = Nautical Miles
+/// 5     x.x   Total cumulative ground distance, nm (NMEA 3.0 and later)
+/// 6     N     N = Nautical Miles (NMEA 3.0 and later)
+/// 7     x.x   Ground distance since reset, nm (NMEA 3.0 and later)
+/// 8     N     N = Nautical Miles (NMEA 3.0 and later)
+pub fn parse_vlw(sentence: NmeaSentence) -> Result<VlwData, NmeaError> {
+    if sentence.message_id != b"VLW" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"VLW",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vlw(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_vlw() {
+        let s = parse_nmea_sentence(b"$IIVLW,7803.2,N,0.00,N*43").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            VlwData {
+                total_water_distance: Some(7803.2),
+                trip_water_distance: Some(0.),
+                total_ground_distance: None,
+                trip_ground_distance: None,
+            },
+            parse_vlw(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$IIVLW,7803.2,N,12.5,N,7900.1,N,13.0,N*48").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            VlwData {
+                total_water_distance: Some(7803.2),
+                trip_water_distance: Some(12.5),
+                total_ground_distance: Some(7900.1),
+                trip_ground_distance: Some(13.),
+            },
+            parse_vlw(s).unwrap()
+        );
+    }
+}
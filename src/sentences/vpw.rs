@@ -0,0 +1,67 @@
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::sentences::utils::speed_to_knots;
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct VpwData {
+    /// Speed parallel to the true wind, knots. Negative when moving downwind.
+    pub speed: Option<f32>,
+}
+
+fn do_parse_vpw(i: &[u8]) -> IResult<&[u8], VpwData> {
+    let (i, speed_knots) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_mps) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    Ok((
+        i,
+        VpwData {
+            speed: speed_knots.or_else(|| speed_mps.map(|speed| speed_to_knots(speed, 'M'))),
+        },
+    ))
+}
+
+/// Parse VPW message
+/// from gpsd:
+/// $--VPW,x.x,N,x.x,M*hh
+/// 1     x.x   Speed, knots, negative means downwind
+/// 2     N     N = Knots
+/// 3     x.x   Speed, meters per second, negative means downwind
+/// 4     M     M = Meters per second
+///
+/// Speed is normalised to knots.
+pub fn parse_vpw(sentence: NmeaSentence) -> Result<VpwData, NmeaError> {
+    if sentence.message_id != b"VPW" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"VPW",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vpw(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_vpw() {
+        let s = parse_nmea_sentence(b"$IIVPW,4.5,N,,M*7D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(VpwData { speed: Some(4.5) }, parse_vpw(s).unwrap());
+
+        let s = parse_nmea_sentence(b"$IIVPW,,N,2.0,M*7E").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_relative_eq!(parse_vpw(s).unwrap().speed.unwrap(), 3.887689);
+    }
+}
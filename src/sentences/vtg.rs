@@ -88,7 +88,7 @@ mod tests {
 
     use crate::parse::{parse_nmea_sentence, NmeaError};
 
-    fn run_parse_vtg(line: &[u8]) -> Result<VtgData, NmeaError> {
+    fn run_parse_vtg(line: &[u8]) -> Result<VtgData, NmeaError<'_>> {
         let s = parse_nmea_sentence(line).expect("VTG sentence initial parse failed");
         assert_eq!(s.checksum, s.calc_checksum());
         parse_vtg(s)
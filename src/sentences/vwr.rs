@@ -0,0 +1,96 @@
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::sentences::utils::speed_to_knots;
+use crate::{parse::NmeaSentence, NmeaError};
+
+#[derive(Debug, PartialEq)]
+pub struct VwrData {
+    /// Apparent wind angle, degrees from the bow, positive to starboard and
+    /// negative to port
+    pub wind_angle: Option<f32>,
+    /// Apparent wind speed, knots
+    pub wind_speed: Option<f32>,
+}
+
+fn do_parse_vwr(i: &[u8]) -> IResult<&[u8], VwrData> {
+    let (i, wind_angle) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, side) = opt(one_of("LR"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_knots) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_mps) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_kph) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('K'))(i)?;
+    Ok((
+        i,
+        VwrData {
+            wind_angle: wind_angle.map(|angle| match side {
+                Some('L') => -angle,
+                _ => angle,
+            }),
+            wind_speed: speed_knots
+                .or_else(|| speed_mps.map(|speed| speed_to_knots(speed, 'M')))
+                .or_else(|| speed_kph.map(|speed| speed_to_knots(speed, 'K'))),
+        },
+    ))
+}
+
+/// Parse VWR message
+/// from gpsd:
+/// $--VWR,x.x,a,x.x,N,x.x,M,x.x,K*hh
+/// 1     x.x   Wind direction magnitude in degrees
+/// 2     a     Wind direction Left/Right of bow
+/// 3     x.x   Speed, knots
+/// 4     N     N = Knots
+/// 5     x.x   Speed, meters/second
+/// 6     M     M = Meters Per Second
+/// 7     x.x   Speed, km/h
+/// 8     K     K = Kilometers Per Hour
+///
+/// Wind speed is normalised to knots.
+pub fn parse_vwr(sentence: NmeaSentence) -> Result<VwrData, NmeaError> {
+    if sentence.message_id != b"VWR" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"VWR",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vwr(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_parse_vwr() {
+        let s = parse_nmea_sentence(b"$IIVWR,045.0,L,12.6,N,6.5,M,23.3,K*52").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            VwrData {
+                wind_angle: Some(-45.),
+                wind_speed: Some(12.6),
+            },
+            parse_vwr(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$IIVWR,,,,N,,M,10.0,K*04").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let vwr = parse_vwr(s).unwrap();
+        assert_eq!(vwr.wind_angle, None);
+        assert_relative_eq!(vwr.wind_speed.unwrap(), 5.399568);
+    }
+}
@@ -0,0 +1,83 @@
+use arrayvec::ArrayString;
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct WcvData {
+    /// Velocity component towards the waypoint, knots
+    pub velocity: Option<f32>,
+    pub waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+fn do_parse_wcv(i: &[u8]) -> Result<WcvData, NmeaError<'_>> {
+    // 1. Velocity
+    let (i, velocity) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. N = Knots
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Waypoint ID
+    let (_i, waypoint_id) = parse_str_field(i)?;
+    // 4. FAA mode indicator (NMEA 2.3 and later, optional)
+
+    Ok(WcvData {
+        velocity,
+        waypoint_id: array_string(waypoint_id)?,
+    })
+}
+
+/// Parse WCV message
+/// from gpsd:
+/// $--WCV,x.x,N,c--c*hh
+/// 1     x.x   Velocity
+/// 2     N     N = knots
+/// 3     c--c  Waypoint ID
+pub fn parse_wcv(sentence: NmeaSentence) -> Result<WcvData, NmeaError> {
+    if sentence.message_id != b"WCV" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"WCV",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_wcv(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_wcv() {
+        let sentence = parse_nmea_sentence(b"$GPWCV,2.6,N,EGLM,A*73").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            WcvData {
+                velocity: Some(2.6),
+                waypoint_id: Some(ArrayString::from("EGLM").unwrap()),
+            },
+            parse_wcv(sentence).unwrap()
+        );
+
+        let sentence = parse_nmea_sentence(b"$GPWCV,,N,,*1B").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            WcvData {
+                velocity: None,
+                waypoint_id: None,
+            },
+            parse_wcv(sentence).unwrap()
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use arrayvec::ArrayString;
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::number::complete::float;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct WncData {
+    /// Distance between the waypoints, nautical miles
+    pub distance: Option<f32>,
+    pub to_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+    pub from_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+fn do_parse_wnc(i: &[u8]) -> Result<WncData, NmeaError<'_>> {
+    // 1. Distance, Nautical Miles
+    let (i, distance_nm) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. N = Nautical Miles
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Distance, Kilometers
+    let (i, distance_km) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. K = Kilometers
+    let (i, _) = opt(char('K'))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. TO Waypoint ID
+    let (i, to_waypoint_id) = parse_str_field(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. FROM Waypoint ID
+    let (_i, from_waypoint_id) = parse_str_field(i)?;
+
+    Ok(WncData {
+        distance: distance_nm.or_else(|| distance_km.map(|km| km / 1.852)),
+        to_waypoint_id: array_string(to_waypoint_id)?,
+        from_waypoint_id: array_string(from_waypoint_id)?,
+    })
+}
+
+/// Parse WNC message
+/// from gpsd:
+/// $--WNC,x.x,N,x.x,K,c--c,c--c*hh
+/// 1     x.x   Distance, Nautical Miles
+/// 2     N     N = Nautical Miles
+/// 3     x.x   Distance, Kilometers
+/// 4     K     K = Kilometers
+/// 5     c--c  TO Waypoint ID
+/// 6     c--c  FROM Waypoint ID
+///
+/// Distance is normalised to nautical miles.
+pub fn parse_wnc(sentence: NmeaSentence) -> Result<WncData, NmeaError> {
+    if sentence.message_id != b"WNC" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"WNC",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_wnc(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_wnc() {
+        let sentence = parse_nmea_sentence(b"$GPWNC,0012.3,N,0022.8,K,TO,FROM*4D").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            WncData {
+                distance: Some(12.3),
+                to_waypoint_id: Some(ArrayString::from("TO").unwrap()),
+                from_waypoint_id: Some(ArrayString::from("FROM").unwrap()),
+            },
+            parse_wnc(sentence).unwrap()
+        );
+
+        let sentence = parse_nmea_sentence(b"$GPWNC,,N,10.0,K,TO,FROM*5A").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_wnc(sentence).unwrap();
+
+        assert_relative_eq!(data.distance.unwrap(), 5.399_568);
+    }
+}
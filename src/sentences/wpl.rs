@@ -0,0 +1,80 @@
+use arrayvec::ArrayString;
+use nom::character::complete::char;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_lat_lon, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct WplData {
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+fn do_parse_wpl(i: &[u8]) -> Result<WplData, NmeaError<'_>> {
+    // 1. Latitude
+    // 2. N or S (North or South)
+    // 3. Longitude
+    // 4. E or W (East or West)
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 5. Waypoint name
+    let (_i, waypoint_id) = parse_str_field(i)?;
+
+    Ok(WplData {
+        latitude: lat_lon.map(|v| v.0),
+        longitude: lat_lon.map(|v| v.1),
+        waypoint_id: array_string(waypoint_id)?,
+    })
+}
+
+/// Parse WPL message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_wpl_waypoint_location
+///
+/// $--WPL,llll.ll,a,yyyyy.yy,a,c--c*hh<CR><LF>
+pub fn parse_wpl(sentence: NmeaSentence) -> Result<WplData, NmeaError> {
+    if sentence.message_id != b"WPL" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"WPL",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_wpl(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_wpl() {
+        let sentence = parse_nmea_sentence(b"$GPWPL,4917.16,N,12310.64,W,003*65").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_wpl(sentence).unwrap();
+
+        assert_relative_eq!(data.latitude.unwrap(), 49. + 17.16 / 60.);
+        assert_relative_eq!(data.longitude.unwrap(), -(123. + 10.64 / 60.));
+        assert_eq!(&data.waypoint_id.unwrap(), "003");
+
+        let sentence = parse_nmea_sentence(b"$GPWPL,,,,,*70").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+        assert_eq!(
+            WplData {
+                latitude: None,
+                longitude: None,
+                waypoint_id: None,
+            },
+            parse_wpl(sentence).unwrap()
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use arrayvec::ArrayString;
+use nom::character::complete::{char, none_of};
+use nom::combinator::opt;
+use nom::multi::many0;
+use nom::number::complete::float;
+use nom::sequence::preceded;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+/// Value of a transducer, normalised to SI units except for angles in
+/// degrees and temperatures in degrees Celsius
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum XdrMeasurement {
+    /// Degrees
+    Angle(f32),
+    /// Degrees Celsius
+    Temperature(f32),
+    /// Meters
+    Displacement(f32),
+    /// Hertz
+    Frequency(f32),
+    /// Percent
+    Humidity(f32),
+    /// Amperes
+    Current(f32),
+    /// Parts per thousand
+    Salinity(f32),
+    /// Newtons
+    Force(f32),
+    /// Pascals
+    Pressure(f32),
+    /// Liters per second
+    FlowRate(f32),
+    /// Revolutions per minute
+    Tachometer(f32),
+    /// Volts
+    Voltage(f32),
+    /// Cubic meters
+    Volume(f32),
+    /// State of a switch or valve
+    Switch(f32),
+    Generic(f32),
+    /// Transducer type or unit that is not known, or a missing value
+    Raw {
+        transducer_type: Option<char>,
+        value: Option<f32>,
+        unit: Option<char>,
+    },
+}
+
+impl XdrMeasurement {
+    fn new(transducer_type: Option<char>, value: Option<f32>, unit: Option<char>) -> Self {
+        let measurement = match (transducer_type, value, unit) {
+            (Some('A'), Some(value), Some('D')) => Some(XdrMeasurement::Angle(value)),
+            (Some('C'), Some(value), Some('C')) => Some(XdrMeasurement::Temperature(value)),
+            (Some('C'), Some(value), Some('F')) => {
+                Some(XdrMeasurement::Temperature((value - 32.) * 5. / 9.))
+            }
+            (Some('C'), Some(value), Some('K')) => {
+                Some(XdrMeasurement::Temperature(value - 273.15))
+            }
+            (Some('D'), Some(value), Some('M')) => Some(XdrMeasurement::Displacement(value)),
+            (Some('F'), Some(value), Some('H')) => Some(XdrMeasurement::Frequency(value)),
+            (Some('H'), Some(value), Some('P')) => Some(XdrMeasurement::Humidity(value)),
+            (Some('I'), Some(value), Some('A')) => Some(XdrMeasurement::Current(value)),
+            (Some('L'), Some(value), Some('S')) => Some(XdrMeasurement::Salinity(value)),
+            (Some('N'), Some(value), Some('N')) => Some(XdrMeasurement::Force(value)),
+            (Some('P'), Some(value), Some('B')) => Some(XdrMeasurement::Pressure(value * 1e5)),
+            (Some('P'), Some(value), Some('P')) => Some(XdrMeasurement::Pressure(value)),
+            (Some('R'), Some(value), Some('l')) => Some(XdrMeasurement::FlowRate(value)),
+            (Some('T'), Some(value), Some('R')) => Some(XdrMeasurement::Tachometer(value)),
+            (Some('U'), Some(value), Some('V')) => Some(XdrMeasurement::Voltage(value)),
+            (Some('V'), Some(value), Some('M')) => Some(XdrMeasurement::Volume(value)),
+            (Some('S'), Some(value), _) => Some(XdrMeasurement::Switch(value)),
+            (Some('G'), Some(value), _) => Some(XdrMeasurement::Generic(value)),
+            _ => None,
+        };
+        measurement.unwrap_or(XdrMeasurement::Raw {
+            transducer_type,
+            value,
+            unit,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct XdrTransducer {
+    pub measurement: XdrMeasurement,
+    pub name: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct XdrData {
+    pub transducers: Vec<XdrTransducer>,
+}
+
+type RawTransducer<'a> = (Option<char>, Option<f32>, Option<char>, Option<&'a str>);
+
+fn parse_transducer(i: &[u8]) -> IResult<&[u8], RawTransducer<'_>> {
+    let (i, transducer_type) = opt(none_of(",*"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, value) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, unit) = opt(none_of(",*"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, name) = parse_str_field(i)?;
+    Ok((i, (transducer_type, value, unit, name)))
+}
+
+fn do_parse_xdr(i: &[u8]) -> Result<XdrData, NmeaError<'_>> {
+    let (i, first) = parse_transducer(i)?;
+    let (_i, rest) = many0(preceded(char(','), parse_transducer))(i)?;
+
+    let transducers = core::iter::once(first)
+        .chain(rest)
+        .map(|(transducer_type, value, unit, name)| {
+            Ok(XdrTransducer {
+                measurement: XdrMeasurement::new(transducer_type, value, unit),
+                name: array_string(name)?,
+            })
+        })
+        .collect::<Result<_, NmeaError>>()?;
+    Ok(XdrData { transducers })
+}
+
+/// Parse XDR message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_xdr_transducer_measurement
+///
+/// $--XDR,a,x.x,a,c--c, ..... *hh<CR><LF>
+///
+/// Each transducer is a group of four fields: type, value, unit and name.
+/// Values of known types and units are normalised, the others are kept raw.
+pub fn parse_xdr(sentence: NmeaSentence) -> Result<XdrData, NmeaError> {
+    if sentence.message_id != b"XDR" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"XDR",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_xdr(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    fn transducer(measurement: XdrMeasurement, name: &str) -> XdrTransducer {
+        XdrTransducer {
+            measurement,
+            name: Some(ArrayString::from(name).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_parse_xdr() {
+        let sentence = parse_nmea_sentence(b"$WIXDR,C,022.0,C,,P,1.0184,B,,H,054.5,P,*4C").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_xdr(sentence).unwrap();
+
+        assert_eq!(data.transducers.len(), 3);
+        assert_eq!(
+            data.transducers[0].measurement,
+            XdrMeasurement::Temperature(22.)
+        );
+        assert_eq!(data.transducers[0].name, None);
+        match data.transducers[1].measurement {
+            XdrMeasurement::Pressure(pressure) => assert_relative_eq!(pressure, 101840.),
+            measurement => panic!("Unexpected measurement {:?}", measurement),
+        }
+        assert_eq!(
+            data.transducers[2].measurement,
+            XdrMeasurement::Humidity(54.5)
+        );
+
+        let sentence = parse_nmea_sentence(b"$IIXDR,A,-1.5,D,PTCH,A,2.3,D,ROLL*74").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            XdrData {
+                transducers: vec![
+                    transducer(XdrMeasurement::Angle(-1.5), "PTCH"),
+                    transducer(XdrMeasurement::Angle(2.3), "ROLL"),
+                ]
+            },
+            parse_xdr(sentence).unwrap()
+        );
+
+        let sentence =
+            parse_nmea_sentence(b"$IIXDR,U,12.6,V,BATT1,I,3.2,A,ALT,T,1850,R,ENG1,G,1,,SW1*2E")
+                .unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            XdrData {
+                transducers: vec![
+                    transducer(XdrMeasurement::Voltage(12.6), "BATT1"),
+                    transducer(XdrMeasurement::Current(3.2), "ALT"),
+                    transducer(XdrMeasurement::Tachometer(1850.), "ENG1"),
+                    transducer(XdrMeasurement::Generic(1.), "SW1"),
+                ]
+            },
+            parse_xdr(sentence).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_xdr_raw() {
+        let sentence =
+            parse_nmea_sentence(b"$IIXDR,X,12.0,Z,CUSTOM,C,77.0,F,ENGINE,U,,V,BATT*57").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_xdr(sentence).unwrap();
+
+        assert_eq!(
+            data.transducers[0],
+            transducer(
+                XdrMeasurement::Raw {
+                    transducer_type: Some('X'),
+                    value: Some(12.),
+                    unit: Some('Z'),
+                },
+                "CUSTOM"
+            )
+        );
+        match data.transducers[1].measurement {
+            XdrMeasurement::Temperature(temperature) => assert_relative_eq!(temperature, 25.),
+            measurement => panic!("Unexpected measurement {:?}", measurement),
+        }
+        assert_eq!(
+            data.transducers[2].measurement,
+            XdrMeasurement::Raw {
+                transducer_type: Some('U'),
+                value: None,
+                unit: Some('V'),
+            }
+        );
+    }
+}
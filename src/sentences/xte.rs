@@ -0,0 +1,107 @@
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::rmb::{parse_steer_direction, SteerDirection};
+use crate::NmeaError;
+
+#[derive(Debug, PartialEq)]
+pub struct XteData {
+    pub valid: bool,
+    /// Cross track error, nautical miles
+    pub cross_track_error: Option<f32>,
+    pub steer_direction: Option<SteerDirection>,
+}
+
+fn do_parse_xte(i: &[u8]) -> IResult<&[u8], XteData> {
+    // 1. Status, V = Loran-C Blink or SNR warning, A = OK
+    let (i, status1) = one_of("AV")(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Status, V = Loran-C Cycle Lock warning flag, A = OK
+    let (i, status2) = one_of("AV")(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Cross Track Error Magnitude
+    let (i, cross_track_error) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Direction to steer, L or R
+    let (i, steer_direction) = parse_steer_direction(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Cross Track Units, N = Nautical miles, K = Kilometers
+    let (i, xte_units) = opt(one_of("NK"))(i)?;
+    // 6. FAA mode indicator (NMEA 2.3 and later, optional)
+
+    Ok((
+        i,
+        XteData {
+            valid: status1 == 'A' && status2 == 'A',
+            cross_track_error: cross_track_error.map(|xte| match xte_units {
+                Some('K') => xte / 1.852,
+                _ => xte,
+            }),
+            steer_direction,
+        },
+    ))
+}
+
+/// Parse XTE message
+/// See: https://gpsd.gitlab.io/gpsd/NMEA.html#_xte_cross_track_error_measured
+///
+/// $--XTE,A,A,x.x,a,N,m*hh<CR><LF>
+///
+/// Cross track error is normalised to nautical miles.
+pub fn parse_xte(sentence: NmeaSentence) -> Result<XteData, NmeaError> {
+    if sentence.message_id != b"XTE" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"XTE",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_xte(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_xte() {
+        let sentence = parse_nmea_sentence(b"$GPXTE,A,A,0.67,L,N,A*02").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            XteData {
+                valid: true,
+                cross_track_error: Some(0.67),
+                steer_direction: Some(SteerDirection::Left),
+            },
+            parse_xte(sentence).unwrap()
+        );
+
+        let sentence = parse_nmea_sentence(b"$GPXTE,A,A,1.852,R,K*4B").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        let data = parse_xte(sentence).unwrap();
+
+        assert_relative_eq!(data.cross_track_error.unwrap(), 1.);
+        assert_eq!(data.steer_direction, Some(SteerDirection::Right));
+
+        let sentence = parse_nmea_sentence(b"$GPXTE,V,V,,,N,S*43").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            XteData {
+                valid: false,
+                cross_track_error: None,
+                steer_direction: None,
+            },
+            parse_xte(sentence).unwrap()
+        );
+    }
+}
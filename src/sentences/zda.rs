@@ -0,0 +1,137 @@
+use chrono::{NaiveDate, NaiveTime};
+use nom::character::complete::{char, digit1, one_of};
+use nom::combinator::{map_res, opt, recognize};
+use nom::sequence::pair;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{number, parse_hms, parse_num};
+use crate::NmeaError;
+
+#[derive(Debug, PartialEq)]
+pub struct ZdaData {
+    pub utc_time: Option<NaiveTime>,
+    pub day: Option<u8>,
+    pub month: Option<u8>,
+    pub year: Option<u16>,
+    pub local_zone_hours: Option<i8>,
+    pub local_zone_minutes: Option<u8>,
+}
+
+impl ZdaData {
+    /// Returns the UTC date if day, month and year are all present and valid
+    pub fn utc_date(&self) -> Option<NaiveDate> {
+        match (self.year, self.month, self.day) {
+            (Some(year), Some(month), Some(day)) => {
+                NaiveDate::from_ymd_opt(i32::from(year), u32::from(month), u32::from(day))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn parse_signed_num(i: &[u8]) -> IResult<&[u8], i8> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), parse_num::<i8>)(i)
+}
+
+fn do_parse_zda(i: &[u8]) -> IResult<&[u8], ZdaData> {
+    let (i, utc_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, day) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, month) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, year) = opt(number::<u16>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, local_zone_hours) = opt(parse_signed_num)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, local_zone_minutes) = opt(number::<u8>)(i)?;
+    Ok((
+        i,
+        ZdaData {
+            utc_time,
+            day,
+            month,
+            year,
+            local_zone_hours,
+            local_zone_minutes,
+        },
+    ))
+}
+
+/// Parse ZDA message
+/// from gpsd:
+/// $GPZDA,160012.71,11,03,2004,-1,00*7D
+/// 1) UTC time (hours, minutes, seconds, may have fractional subsecond)
+/// 2) Day, 01 to 31
+/// 3) Month, 01 to 12
+/// 4) Year (4 digits)
+/// 5) Local zone description, 00 to +- 13 hours
+/// 6) Local zone minutes description, apply same sign as local hours
+/// 7) Checksum
+///
+/// Unlike RMC, the year is transmitted with all 4 digits, so the date
+/// from ZDA is not ambiguous regarding the century.
+pub fn parse_zda(sentence: NmeaSentence) -> Result<ZdaData, NmeaError> {
+    if sentence.message_id != b"ZDA" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"ZDA",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_zda(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_zda() {
+        let s = parse_nmea_sentence(b"$GNZDA,181604.00,12,09,2018,00,00*73").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let zda = parse_zda(s).unwrap();
+        assert_eq!(
+            ZdaData {
+                utc_time: Some(NaiveTime::from_hms_opt(18, 16, 4).unwrap()),
+                day: Some(12),
+                month: Some(9),
+                year: Some(2018),
+                local_zone_hours: Some(0),
+                local_zone_minutes: Some(0),
+            },
+            zda
+        );
+        assert_eq!(
+            zda.utc_date(),
+            Some(NaiveDate::from_ymd_opt(2018, 9, 12).unwrap())
+        );
+
+        let s = parse_nmea_sentence(b"$GPZDA,160012.71,11,03,2004,-1,00*7D").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let zda = parse_zda(s).unwrap();
+        assert_eq!(zda.local_zone_hours, Some(-1));
+        assert_eq!(
+            zda.utc_date(),
+            Some(NaiveDate::from_ymd_opt(2004, 3, 11).unwrap())
+        );
+
+        let s = parse_nmea_sentence(b"$GPZDA,,,,,,*48").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let zda = parse_zda(s).unwrap();
+        assert_eq!(
+            ZdaData {
+                utc_time: None,
+                day: None,
+                month: None,
+                year: None,
+                local_zone_hours: None,
+                local_zone_minutes: None,
+            },
+            zda
+        );
+        assert_eq!(zda.utc_date(), None);
+    }
+}
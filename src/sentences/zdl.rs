@@ -0,0 +1,96 @@
+use chrono::Duration;
+use nom::character::complete::{char, one_of};
+use nom::combinator::opt;
+use nom::number::complete::float;
+use nom::IResult;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::parse_duration;
+use crate::NmeaError;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ZdlPointType {
+    Collision,
+    TurningPoint,
+    Reference,
+    Wheelover,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ZdlData {
+    pub time_to_point: Option<Duration>,
+    /// Distance to the point, nautical miles
+    pub distance: Option<f32>,
+    pub point_type: Option<ZdlPointType>,
+}
+
+fn do_parse_zdl(i: &[u8]) -> IResult<&[u8], ZdlData> {
+    let (i, time_to_point) = opt(parse_duration)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, distance) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, point_type) = opt(one_of("CTRW"))(i)?;
+    Ok((
+        i,
+        ZdlData {
+            time_to_point,
+            distance,
+            point_type: point_type.map(|point_type| match point_type {
+                'C' => ZdlPointType::Collision,
+                'T' => ZdlPointType::TurningPoint,
+                'R' => ZdlPointType::Reference,
+                'W' => ZdlPointType::Wheelover,
+                _ => unreachable!(),
+            }),
+        },
+    ))
+}
+
+/// Parse ZDL message
+/// from gpsd:
+/// $--ZDL,hhmmss.ss,x.x,a*hh
+/// 1     hhmmss.ss  Time to point
+/// 2     x.x        Distance to point, nautical miles
+/// 3     a          Type of point, C = Collision, T = Turning point,
+///                  R = Reference, W = Wheelover
+pub fn parse_zdl(sentence: NmeaSentence) -> Result<ZdlData, NmeaError> {
+    if sentence.message_id != b"ZDL" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"ZDL",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_zdl(sentence.data)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_zdl() {
+        let s = parse_nmea_sentence(b"$GPZDL,001215.0,2.8,T*00").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            ZdlData {
+                time_to_point: Some(Duration::minutes(12) + Duration::seconds(15)),
+                distance: Some(2.8),
+                point_type: Some(ZdlPointType::TurningPoint),
+            },
+            parse_zdl(s).unwrap()
+        );
+
+        let s = parse_nmea_sentence(b"$GPZDL,,,*69").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            ZdlData {
+                time_to_point: None,
+                distance: None,
+                point_type: None,
+            },
+            parse_zdl(s).unwrap()
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use arrayvec::ArrayString;
+use chrono::{Duration, NaiveTime};
+use nom::character::complete::char;
+use nom::combinator::opt;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_duration, parse_hms, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct ZfoData {
+    pub fix_time: Option<NaiveTime>,
+    /// Time elapsed since leaving the origin waypoint
+    pub elapsed_time: Option<Duration>,
+    pub origin_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+fn do_parse_zfo(i: &[u8]) -> Result<ZfoData, NmeaError<'_>> {
+    // 1. UTC time of observation
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Elapsed time
+    let (i, elapsed_time) = opt(parse_duration)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Origin waypoint ID
+    let (_i, origin_waypoint_id) = parse_str_field(i)?;
+
+    Ok(ZfoData {
+        fix_time,
+        elapsed_time,
+        origin_waypoint_id: array_string(origin_waypoint_id)?,
+    })
+}
+
+/// Parse ZFO message
+/// from gpsd:
+/// $--ZFO,hhmmss.ss,hhmmss.ss,c--c*hh
+/// 1     hhmmss.ss  UTC time of observation
+/// 2     hhmmss.ss  Elapsed time
+/// 3     c--c       Origin waypoint ID
+pub fn parse_zfo(sentence: NmeaSentence) -> Result<ZfoData, NmeaError> {
+    if sentence.message_id != b"ZFO" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"ZFO",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_zfo(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_zfo() {
+        let sentence = parse_nmea_sentence(b"$GPZFO,145832.12,042359.17,WPT*3E").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            ZfoData {
+                fix_time: NaiveTime::from_hms_milli_opt(14, 58, 32, 120),
+                elapsed_time: Some(
+                    Duration::hours(4) + Duration::minutes(23) + Duration::milliseconds(59_170)
+                ),
+                origin_waypoint_id: Some(ArrayString::from("WPT").unwrap()),
+            },
+            parse_zfo(sentence).unwrap()
+        );
+    }
+}
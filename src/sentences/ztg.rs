@@ -0,0 +1,81 @@
+use arrayvec::ArrayString;
+use chrono::{Duration, NaiveTime};
+use nom::character::complete::char;
+use nom::combinator::opt;
+
+use crate::parse::NmeaSentence;
+use crate::sentences::utils::{array_string, parse_duration, parse_hms, parse_str_field};
+use crate::NmeaError;
+
+const MAX_LEN: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct ZtgData {
+    pub fix_time: Option<NaiveTime>,
+    /// Time remaining to reach the destination waypoint
+    pub time_to_go: Option<Duration>,
+    pub destination_waypoint_id: Option<ArrayString<[u8; MAX_LEN]>>,
+}
+
+fn do_parse_ztg(i: &[u8]) -> Result<ZtgData, NmeaError<'_>> {
+    // 1. UTC time of observation
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Time to go
+    let (i, time_to_go) = opt(parse_duration)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Destination waypoint ID
+    let (_i, destination_waypoint_id) = parse_str_field(i)?;
+
+    Ok(ZtgData {
+        fix_time,
+        time_to_go,
+        destination_waypoint_id: array_string(destination_waypoint_id)?,
+    })
+}
+
+/// Parse ZTG message
+/// from gpsd:
+/// $--ZTG,hhmmss.ss,hhmmss.ss,c--c*hh
+/// 1     hhmmss.ss  UTC time of observation
+/// 2     hhmmss.ss  Time remaining
+/// 3     c--c       Destination waypoint ID
+pub fn parse_ztg(sentence: NmeaSentence) -> Result<ZtgData, NmeaError> {
+    if sentence.message_id != b"ZTG" {
+        Err(NmeaError::WrongSentenceHeader {
+            expected: b"ZTG",
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_ztg(sentence.data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::parse_nmea_sentence;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_ztg() {
+        let sentence = parse_nmea_sentence(b"$GPZTG,145832.12,042359.17,WPT*24").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(
+            ZtgData {
+                fix_time: NaiveTime::from_hms_milli_opt(14, 58, 32, 120),
+                time_to_go: Some(
+                    Duration::hours(4) + Duration::minutes(23) + Duration::milliseconds(59_170)
+                ),
+                destination_waypoint_id: Some(ArrayString::from("WPT").unwrap()),
+            },
+            parse_ztg(sentence).unwrap()
+        );
+
+        let sentence = parse_nmea_sentence(b"$GPZTG,145832.12,,WPT*05").unwrap();
+        assert_eq!(sentence.checksum, sentence.calc_checksum());
+
+        assert_eq!(parse_ztg(sentence).unwrap().time_to_go, None);
+    }
+}
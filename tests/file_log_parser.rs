@@ -31,7 +31,7 @@ fn test_parse_file_log() {
         .unwrap_or_else(|err| panic!("process file failed with error '{}'", err));
 
     let expected: Vec<_> =
-        BufReader::new(File::open(&Path::new("tests").join("nmea1.log.expected")).unwrap())
+        BufReader::new(File::open(Path::new("tests").join("nmea1.log.expected")).unwrap())
             .lines()
             .map(|v| v.unwrap())
             .collect();
@@ -40,7 +40,7 @@ fn test_parse_file_log() {
 
 #[test]
 fn test_parse_issue_2() {
-    let mut input = BufReader::new(File::open(&Path::new("tests").join("nmea2.log")).unwrap());
+    let mut input = BufReader::new(File::open(Path::new("tests").join("nmea2.log")).unwrap());
     let mut nmea = nmea::Nmea::new();
     for _ in 0..100 {
         let mut buffer = String::new();